@@ -3,22 +3,111 @@ use config_manager::SystemConfig;
 use dex_client::{BirdEyeClient, TopTrader, TrendingToken as BirdEyeTrendingToken, GeneralTraderTransaction, GainerLoser, DexScreenerClient, DexScreenerBoostedToken, NewListingToken, NewListingTokenFilter};
 use persistence_layer::{RedisClient, DiscoveredWalletToken};
 // NewFinancialEvent/NewEventType imports removed - using GeneralTraderTransaction directly
+use futures_util::{SinkExt, StreamExt};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use lru::LruCache;
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
-use tokio::sync::Mutex;
+use std::time::{Duration, Instant};
+use tokio::sync::{Mutex, Semaphore};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::sync::broadcast;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, warn};
 
+/// Capacity of the live `DiscoveryEvent` broadcast channel. Lagging subscribers drop the
+/// oldest events rather than stalling discovery, per `tokio::sync::broadcast` semantics.
+const DISCOVERY_EVENT_CHANNEL_CAPACITY: usize = 1024;
+
+/// Which discovery source produced a `DiscoveryEvent`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiscoverySource {
+    Trending,
+    Gainer,
+    Boosted,
+    NewListing,
+    Streaming,
+    /// Not a fetch source — marks a `DiscoveryEvent` reporting that a full per-chain cycle
+    /// finished, rather than a specific token or trader.
+    Cycle,
+    /// Traders were fetched because a pending `PriceTrigger` crossed, not from a fresh poll.
+    Triggered,
+}
+
+/// Which lifecycle moment of the discovery pipeline a `DiscoveryEvent` reports. All moments
+/// ride the same broadcast channel and struct shape, mirroring the `tx_price_feed`/
+/// `tx_user_feed` pattern in the 10101 coordinator, so subscribers filter by `kind` instead of
+/// juggling several channels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiscoveryEventKind {
+    /// A token was surfaced by a polling discovery source, before its traders are fetched.
+    TokenDiscovered,
+    /// Quality traders were found for a token (`count` traders).
+    TradersFound,
+    /// Wallet-token pairs were pushed to the Redis analysis queue (`count` pairs).
+    PairsEnqueued,
+    /// A full discovery cycle for a chain finished (`count` wallets discovered overall).
+    CycleCompleted,
+}
+
+/// Emitted as notable moments of the discovery pipeline happen, so external consumers (a
+/// webhook, a dashboard, alerting) can react in real time without polling `get_discovery_stats`.
+#[derive(Debug, Clone)]
+pub struct DiscoveryEvent {
+    pub kind: DiscoveryEventKind,
+    pub source: DiscoverySource,
+    pub chain: String,
+    pub token_address: String,
+    pub token_symbol: String,
+    /// Meaning depends on `kind`: traders found, pairs enqueued, or wallets discovered this
+    /// cycle. Unused (`0`) for `TokenDiscovered`.
+    pub count: usize,
+    pub occurred_at: chrono::DateTime<chrono::Utc>,
+}
+
 // BirdEyeTrendingConfig removed - now uses SystemConfig directly
 
-/// Orchestrates trending token discovery and top trader identification using BirdEye API + DexScreener boosted tokens
+/// Well-known Solana DEX program IDs monitored by the streaming discovery source when a
+/// chain doesn't configure its own `program_ids_by_chain` entry.
+const DEFAULT_DEX_PROGRAM_IDS: &[&str] = &[
+    "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8", // Raydium AMM
+    "whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc",  // Orca Whirlpool
+    "LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo",  // Meteora DLMM
+];
+
+/// Orchestrates trending token discovery and top trader identification using BirdEye API + DexScreener boosted tokens.
+/// Also runs a push-based streaming discovery source (`config.streaming`) alongside the REST polling cycle,
+/// which watches tokens the polling sources have recently surfaced for sub-second trader discovery.
 pub struct BirdEyeTrendingOrchestrator {
     config: SystemConfig,
     birdeye_client: BirdEyeClient,
     dexscreener_client: Option<DexScreenerClient>,
     redis_client: Arc<Mutex<Option<RedisClient>>>,
+    /// Tracks whether the orchestrator has been started and not yet stopped, for
+    /// `get_discovery_stats()` reporting. Shutdown signaling itself lives in `shutdown_token`.
     is_running: Arc<Mutex<bool>>,
+    /// Replaced with a fresh token on every `start()` and cancelled by `stop()`. Every
+    /// interruptible sleep and per-item loop observes a clone of this (or a child token derived
+    /// from it) via `tokio::select!`/`is_cancelled()` instead of polling `is_running` in a tight
+    /// loop, so a single cancel propagates instantly and lock-free to every in-flight task.
+    shutdown_token: Arc<Mutex<CancellationToken>>,
+    dedup_cache: Arc<DedupCache>,
+    /// Tokens surfaced by the polling discovery sources recently enough that the streaming
+    /// `logsSubscribe` task should treat fresh swaps on them as real-time discoveries.
+    watched_tokens: Arc<WatchedTokenRegistry>,
+    /// Last successful fire time per `"{source}:{chain}"` key, consulted by `is_source_due`
+    /// so each discovery source can run on its own schedule instead of lockstep.
+    last_run_at: Arc<Mutex<HashMap<String, chrono::DateTime<chrono::Utc>>>>,
+    metrics: Arc<MetricsRegistry>,
+    /// Fan-out feed of `DiscoveryEvent`s for live consumers; see `subscribe()`. Sender is kept
+    /// around even with zero subscribers since `broadcast::Sender::send` only errors on that.
+    event_tx: broadcast::Sender<DiscoveryEvent>,
 }
 
 impl BirdEyeTrendingOrchestrator {
@@ -45,15 +134,56 @@ impl BirdEyeTrendingOrchestrator {
             None
         };
         
+        let dedup_cache = Arc::new(DedupCache::new(
+            config.birdeye.dedup_cache_capacity,
+            Duration::from_secs(config.birdeye.dedup_cache_ttl_seconds),
+        ));
+
+        let watched_tokens = Arc::new(WatchedTokenRegistry::new(
+            config.streaming.watched_token_capacity,
+            Duration::from_secs(config.streaming.watched_token_ttl_seconds),
+        ));
+
+        let (event_tx, _) = broadcast::channel(DISCOVERY_EVENT_CHANNEL_CAPACITY);
+
         Ok(Self {
             config,
             birdeye_client,
             dexscreener_client,
             redis_client: Arc::new(Mutex::new(redis_client)),
             is_running: Arc::new(Mutex::new(false)),
+            shutdown_token: Arc::new(Mutex::new(CancellationToken::new())),
+            dedup_cache,
+            watched_tokens,
+            last_run_at: Arc::new(Mutex::new(HashMap::new())),
+            metrics: Arc::new(MetricsRegistry::new()),
+            event_tx,
         })
     }
 
+    /// Subscribe to the live feed of `DiscoveryEvent`s emitted as the pipeline discovers
+    /// tokens, finds their traders, enqueues wallet-token pairs, and completes cycles — see
+    /// `DiscoveryEventKind`. Subscribers that fall behind `DISCOVERY_EVENT_CHANNEL_CAPACITY`
+    /// events drop the oldest ones (`broadcast::error::RecvError::Lagged`) rather than
+    /// blocking discovery.
+    pub fn subscribe(&self) -> broadcast::Receiver<DiscoveryEvent> {
+        self.event_tx.subscribe()
+    }
+
+    /// Best-effort emit of a `DiscoveryEvent`. `broadcast::Sender::send` only errors when
+    /// there are zero subscribers, which is the common case and not worth logging about.
+    fn emit_discovery_event(&self, kind: DiscoveryEventKind, source: DiscoverySource, chain: &str, token_address: &str, token_symbol: &str, count: usize) {
+        let _ = self.event_tx.send(DiscoveryEvent {
+            kind,
+            source,
+            chain: chain.to_string(),
+            token_address: token_address.to_string(),
+            token_symbol: token_symbol.to_string(),
+            count,
+            occurred_at: chrono::Utc::now(),
+        });
+    }
+
     /// Start the trending discovery loop
     pub async fn start(&self) -> Result<()> {
         let mut is_running = self.is_running.lock().await;
@@ -64,22 +194,41 @@ impl BirdEyeTrendingOrchestrator {
         *is_running = true;
         drop(is_running);
 
+        // A cancelled token never un-cancels, so a restart after `stop()` needs a fresh one.
+        let token = {
+            let mut guard = self.shutdown_token.lock().await;
+            *guard = CancellationToken::new();
+            guard.clone()
+        };
+
         info!("🚀 Starting Enhanced Multi-Sort BirdEye Discovery Orchestrator");
-        info!("📋 Enhanced Discovery: 3 sorting strategies (rank + volume + liquidity), unlimited tokens, max_traders_per_token={}, cycle_interval={}s", 
-              self.config.birdeye.max_traders_per_token, 60);
+        info!("📋 Enhanced Discovery: 3 sorting strategies (rank + volume + liquidity), unlimited tokens, max_traders_per_token={}, cycle_interval={}s",
+              self.config.birdeye.max_traders_per_token, 15);
+
+        // Push-based streaming discovery runs concurrently with the polling loop below and
+        // reconnects on its own; we don't join these handles, only let them observe the token.
+        let _streaming_handles = self.spawn_streaming_tasks(token.clone());
+
+        // Serve Prometheus-format metrics on a background task for the lifetime of the run.
+        if self.config.metrics.enabled {
+            let metrics = self.metrics.clone();
+            let metrics_token = token.clone();
+            let port = self.config.metrics.port;
+            tokio::spawn(async move {
+                if let Err(e) = serve_metrics(metrics, metrics_token, port).await {
+                    error!("❌ Metrics server on port {} exited with error: {}", port, e);
+                }
+            });
+        }
 
         loop {
-            // Check if we should stop
-            {
-                let is_running = self.is_running.lock().await;
-                if !*is_running {
-                    info!("🛑 BirdEye trending orchestrator stopped");
-                    break;
-                }
+            if token.is_cancelled() {
+                info!("🛑 BirdEye trending orchestrator stopped");
+                break;
             }
 
             // Execute one cycle
-            match self.execute_discovery_cycle().await {
+            match self.execute_discovery_cycle_with_token(&token).await {
                 Ok(discovered_wallets) => {
                     if discovered_wallets > 0 {
                         info!("✅ Cycle completed: discovered {} quality wallets", discovered_wallets);
@@ -92,244 +241,334 @@ impl BirdEyeTrendingOrchestrator {
                 }
             }
 
-            // Wait before next cycle (interruptible sleep)
-            let sleep_duration = Duration::from_secs(60); // BirdEye polling interval
-            let mut interval = tokio::time::interval(Duration::from_millis(500)); // Check stop flag every 500ms
-            let start_time = std::time::Instant::now();
-            
-            loop {
-                interval.tick().await;
-                
-                // Check if we should stop during sleep
-                {
-                    let is_running = self.is_running.lock().await;
-                    if !*is_running {
-                        info!("🛑 Stop requested during sleep, breaking out early");
-                        return Ok(());
-                    }
-                }
-                
-                // Check if we've slept long enough
-                if start_time.elapsed() >= sleep_duration {
-                    break;
+            // Wait before checking sources again. This is deliberately shorter than any
+            // individual source's cadence now that each one is gated by its own schedule
+            // in `execute_discovery_cycle_for_chain` (`is_source_due`/`schedule_for`) -
+            // ticking the outer loop every 15s just gives the scheduler enough resolution
+            // to hit "fixed UTC time" sweeps without waiting up to a minute.
+            tokio::select! {
+                _ = token.cancelled() => {
+                    info!("🛑 Stop requested during sleep, breaking out early");
+                    return Ok(());
                 }
+                _ = tokio::time::sleep(Duration::from_secs(15)) => {}
             }
         }
 
+        *self.is_running.lock().await = false;
         Ok(())
     }
 
     /// Stop the trending discovery loop
     pub async fn stop(&self) {
-        let mut is_running = self.is_running.lock().await;
-        *is_running = false;
+        *self.is_running.lock().await = false;
+        self.shutdown_token.lock().await.cancel();
         info!("🛑 BirdEye trending orchestrator stop requested");
     }
 
-    /// Execute one complete discovery cycle with enhanced multi-source strategy
-    pub async fn execute_discovery_cycle(&self) -> Result<usize> {
-        // Set is_running to true for this cycle
-        {
-            let mut is_running = self.is_running.lock().await;
-            *is_running = true;
+    /// Spawn one long-lived `logsSubscribe` task per enabled chain that has a streaming
+    /// WebSocket endpoint configured. Each task reconnects with exponential backoff and
+    /// exits on its own once `token` is cancelled, so nothing here needs joining.
+    fn spawn_streaming_tasks(&self, token: CancellationToken) -> Vec<tokio::task::JoinHandle<()>> {
+        if !self.config.streaming.enabled {
+            debug!("⭕ Streaming discovery disabled, skipping logsSubscribe tasks");
+            return Vec::new();
         }
-        
+
+        self.config
+            .multichain
+            .enabled_chains
+            .iter()
+            .filter_map(|chain| {
+                let ws_url = self.config.streaming.ws_url_by_chain.get(chain)?.clone();
+                let program_ids = self
+                    .config
+                    .streaming
+                    .program_ids_by_chain
+                    .get(chain)
+                    .cloned()
+                    .unwrap_or_else(|| DEFAULT_DEX_PROGRAM_IDS.iter().map(|s| s.to_string()).collect());
+
+                let chain = chain.clone();
+                let token = token.clone();
+                let redis_client = self.redis_client.clone();
+                let dedup_cache = self.dedup_cache.clone();
+                let watched_tokens = self.watched_tokens.clone();
+                let event_tx = self.event_tx.clone();
+
+                info!("📡 Spawning streaming logsSubscribe task for chain {} ({} program ids)", chain, program_ids.len());
+                Some(tokio::spawn(run_log_subscription(chain, ws_url, program_ids, token, redis_client, dedup_cache, watched_tokens, event_tx)))
+            })
+            .collect()
+    }
+
+    /// Resolve the configured schedule for a named discovery source (`trending`, `gainers`,
+    /// `boosted`, `new_listing`). Falls back to a 60s interval, matching the pre-scheduler
+    /// behavior, when nothing is configured.
+    fn schedule_for(&self, source: &str) -> ScheduleSpec {
+        let schedule = &self.config.schedule;
+        let (interval_seconds, daily_at_utc) = match source {
+            "trending" => (schedule.trending_interval_seconds, &schedule.trending_daily_at_utc),
+            "gainers" => (schedule.gainers_interval_seconds, &schedule.gainers_daily_at_utc),
+            "boosted" => (schedule.boosted_interval_seconds, &schedule.boosted_daily_at_utc),
+            "new_listing" => (schedule.new_listing_interval_seconds, &schedule.new_listing_daily_at_utc),
+            _ => (60, &None),
+        };
+
+        if let Some(daily_at_utc) = daily_at_utc {
+            if let Some(spec) = ScheduleSpec::daily_at_utc(daily_at_utc) {
+                return spec;
+            }
+            warn!("⚠️ Invalid daily_at_utc '{}' for source {}, falling back to interval", daily_at_utc, source);
+        }
+
+        ScheduleSpec::Interval(Duration::from_secs(if interval_seconds > 0 { interval_seconds } else { 60 }))
+    }
+
+    /// Whether `source`/`chain` is due to run again, based on its last successful fire
+    /// time and its configured `ScheduleSpec`. A source that has never run is always due.
+    async fn is_source_due(&self, source: &str, chain: &str) -> bool {
+        let key = format!("{}:{}", source, chain);
+        let last_run_at = self.last_run_at.lock().await;
+        match last_run_at.get(&key) {
+            Some(last_run) => self.schedule_for(source).next_fire(*last_run) <= chrono::Utc::now(),
+            None => true,
+        }
+    }
+
+    /// Record that `source`/`chain` just fired, so the next `is_source_due` check schedules
+    /// off of this run rather than the previous one.
+    async fn mark_source_ran(&self, source: &str, chain: &str) {
+        let key = format!("{}:{}", source, chain);
+        self.last_run_at.lock().await.insert(key, chrono::Utc::now());
+    }
+
+    /// Execute one complete discovery cycle with enhanced multi-source strategy. Runs against
+    /// whatever shutdown token is current at call time - if the orchestrator hasn't been
+    /// `start()`-ed this is never cancelled, which is fine for a one-off manual cycle.
+    pub async fn execute_discovery_cycle(&self) -> Result<usize> {
+        let token = self.shutdown_token.lock().await.clone();
+        self.execute_discovery_cycle_with_token(&token).await
+    }
+
+    async fn execute_discovery_cycle_with_token(&self, shutdown_token: &CancellationToken) -> Result<usize> {
         info!("🔄 Starting Enhanced Multichain Discovery Cycle");
         debug!("📊 Discovery sources: 1) Paginated trending tokens (unlimited), 2) Paginated gainers (3 timeframes), 3) DexScreener boosted");
-        
+
         let mut total_discovered_wallets = 0;
-        
+
         // Iterate through all enabled chains
         for chain in &self.config.multichain.enabled_chains {
             info!("🔗 Processing chain: {}", chain);
-            
-            total_discovered_wallets += self.execute_discovery_cycle_for_chain(chain).await?;
-            
+
+            total_discovered_wallets += self.execute_discovery_cycle_for_chain(chain, shutdown_token).await?;
+
             // Check if we should stop between chains
-            {
-                let is_running = self.is_running.lock().await;
-                if !*is_running {
-                    info!("🛑 Stop requested between chains, breaking out");
-                    break;
-                }
+            if shutdown_token.is_cancelled() {
+                info!("🛑 Stop requested between chains, breaking out");
+                break;
             }
         }
-        
-        info!("✅ Multichain discovery cycle completed: {} total wallets discovered across {} chains", 
+
+        info!("✅ Multichain discovery cycle completed: {} total wallets discovered across {} chains",
               total_discovered_wallets, self.config.multichain.enabled_chains.len());
-        
-        // Reset is_running flag after cycle completes
-        {
-            let mut is_running = self.is_running.lock().await;
-            *is_running = false;
-        }
-        
+
         Ok(total_discovered_wallets)
     }
-    
-    /// Execute discovery cycle for a specific chain
-    async fn execute_discovery_cycle_for_chain(&self, chain: &str) -> Result<usize> {
-        info!("🔄 Starting discovery cycle for chain: {}", chain);
-        
-        // Step 1: Get trending tokens using enhanced multi-sort discovery for this chain
-        let trending_tokens = self.get_trending_tokens_for_chain(chain).await?;
-        if trending_tokens.is_empty() {
-            debug!("📊 No trending tokens found from multi-sort discovery");
-            return Ok(0);
-        }
 
-        info!("📈 Paginated trending discovery: {} tokens (unlimited processing)", trending_tokens.len());
-        
-        // Safety mechanism: warn if processing a very large number of tokens
-        if trending_tokens.len() > 1000 {
-            warn!("⚠️ Processing {} trending tokens - this may take longer and use more API calls", trending_tokens.len());
+    /// Execute discovery cycle for a specific chain, recording total cycle wall time
+    /// regardless of which branch below returns.
+    async fn execute_discovery_cycle_for_chain(&self, chain: &str, shutdown_token: &CancellationToken) -> Result<usize> {
+        let cycle_started = Instant::now();
+        let result = self.execute_discovery_cycle_for_chain_inner(chain, shutdown_token).await;
+        self.metrics.observe_cycle_duration(chain, cycle_started.elapsed()).await;
+        if let Ok(total_discovered_wallets) = result {
+            self.emit_discovery_event(DiscoveryEventKind::CycleCompleted, DiscoverySource::Cycle, chain, "", "", total_discovered_wallets);
         }
+        result
+    }
+
+    async fn execute_discovery_cycle_for_chain_inner(&self, chain: &str, shutdown_token: &CancellationToken) -> Result<usize> {
+        info!("🔄 Starting discovery cycle for chain: {}", chain);
 
         let mut total_discovered_wallets = 0;
 
-        // Step 2: For each trending token, get top traders
-        for (i, token) in trending_tokens.iter().enumerate() {
-            // Check if we should stop before processing each token
-            {
-                let is_running = self.is_running.lock().await;
-                if !*is_running {
-                    info!("🛑 Stop requested during token processing, breaking out of loop at token {}/{}", 
-                          i + 1, trending_tokens.len());
-                    break;
-                }
-            }
+        // Step 1+2: Get trending tokens using enhanced multi-sort discovery and their top
+        // traders, gated by the "trending" schedule so this (expensive: 15 calls) source
+        // doesn't have to share the cheaper sources' cadence.
+        if self.is_source_due("trending", chain).await {
+            let trending_tokens = self.get_trending_tokens_for_chain(chain).await?;
+            if trending_tokens.is_empty() {
+                debug!("📊 No trending tokens found from multi-sort discovery");
+            } else {
+                info!("📈 Paginated trending discovery: {} tokens (unlimited processing)", trending_tokens.len());
 
-            debug!("🎯 Processing token {}/{}: {} ({})", 
-                   i + 1, trending_tokens.len(), token.symbol, token.address);
+                // Safety mechanism: warn if processing a very large number of tokens
+                if trending_tokens.len() > 1000 {
+                    warn!("⚠️ Processing {} trending tokens - this may take longer and use more API calls", trending_tokens.len());
+                }
 
-            match self.get_top_traders_for_token(&token.address, chain).await {
-                Ok(top_traders) => {
-                    if !top_traders.is_empty() {
-                        info!("👤 Found {} quality traders for {} ({})", 
-                              top_traders.len(), token.symbol, token.address);
+                for (i, token) in trending_tokens.iter().enumerate() {
+                    // Check if we should stop before processing each token
+                    if shutdown_token.is_cancelled() {
+                        info!("🛑 Stop requested during token processing, breaking out of loop at token {}/{}",
+                              i + 1, trending_tokens.len());
+                        break;
+                    }
 
-                        // Step 3: Push quality wallet-token pairs to Redis for P&L analysis
-                        match self.push_wallet_token_pairs_to_queue(&top_traders, token, chain).await {
-                            Ok(pushed_count) => {
-                                total_discovered_wallets += pushed_count;
-                                debug!("📤 Pushed {} wallets to analysis queue for {}", 
-                                       pushed_count, token.symbol);
-                            }
-                            Err(e) => {
-                                warn!("❌ Failed to push wallets for {}: {}", token.symbol, e);
+                    debug!("🎯 Processing token {}/{}: {} ({})",
+                           i + 1, trending_tokens.len(), token.symbol, token.address);
+
+                    // Let the streaming logsSubscribe task surface real-time swaps on this token too.
+                    self.watched_tokens.watch(&token.address).await;
+                    self.emit_discovery_event(DiscoveryEventKind::TokenDiscovered, DiscoverySource::Trending, chain, &token.address, &token.symbol, 0);
+
+                    match self.get_top_traders_for_token(&token.address, chain).await {
+                        Ok(top_traders) => {
+                            if !top_traders.is_empty() {
+                                info!("👤 Found {} quality traders for {} ({})",
+                                      top_traders.len(), token.symbol, token.address);
+                                self.emit_discovery_event(DiscoveryEventKind::TradersFound, DiscoverySource::Trending, chain, &token.address, &token.symbol, top_traders.len());
+
+                                // Step 3: Push quality wallet-token pairs to Redis for P&L analysis
+                                match self.push_wallet_token_pairs_to_queue(&top_traders, token, chain, DiscoverySource::Trending).await {
+                                    Ok(pushed_count) => {
+                                        total_discovered_wallets += pushed_count;
+                                        debug!("📤 Pushed {} wallets to analysis queue for {}",
+                                               pushed_count, token.symbol);
+                                    }
+                                    Err(e) => {
+                                        warn!("❌ Failed to push wallets for {}: {}", token.symbol, e);
+                                    }
+                                }
+                            } else {
+                                debug!("⭕ No quality traders found for {} ({})", token.symbol, token.address);
                             }
                         }
-                    } else {
-                        debug!("⭕ No quality traders found for {} ({})", token.symbol, token.address);
+                        Err(e) => {
+                            warn!("❌ Failed to get top traders for {} ({}): {}", token.symbol, token.address, e);
+                        }
                     }
-                }
-                Err(e) => {
-                    warn!("❌ Failed to get top traders for {} ({}): {}", token.symbol, token.address, e);
-                }
-            }
 
-            // Rate limiting between tokens (interruptible)
-            if i < trending_tokens.len() - 1 {
-                // Make this sleep interruptible by checking stop flag every 100ms
-                let sleep_duration = Duration::from_millis(500);
-                let check_interval = Duration::from_millis(100);
-                let start_time = std::time::Instant::now();
-                
-                while start_time.elapsed() < sleep_duration {
-                    tokio::time::sleep(check_interval).await;
-                    
-                    // Check if we should stop during rate limiting sleep
-                    {
-                        let is_running = self.is_running.lock().await;
-                        if !*is_running {
-                            info!("🛑 Stop requested during trending token rate limiting, breaking out early");
-                            return Ok(total_discovered_wallets);
+                    // Rate limiting between tokens (interruptible). A cancel breaks out of this
+                    // source's loop; the cancellation check right after it then ends the chain
+                    // cycle before gainers/boosted/new-listing/triggers run.
+                    if i < trending_tokens.len() - 1 {
+                        tokio::select! {
+                            _ = shutdown_token.cancelled() => {
+                                info!("🛑 Stop requested during trending token rate limiting, breaking out early");
+                                break;
+                            }
+                            _ = tokio::time::sleep(Duration::from_millis(500)) => {}
                         }
                     }
                 }
             }
+            self.mark_source_ran("trending", chain).await;
+        } else {
+            debug!("⏱️ Trending discovery not due yet for chain {}", chain);
         }
 
-        // Step 3: Get top gainers across different timeframes with pagination for this chain
-        info!("🏆 Starting paginated multi-timeframe gainers discovery for chain: {}", chain);
-        
-        match self.get_top_gainers_for_chain(chain).await {
-            Ok(gainers) => {
-                if !gainers.is_empty() {
-                    info!("💰 Found {} top gainers across all timeframes for chain {}", gainers.len(), chain);
-                    
-                    // Convert gainers to wallet-token pairs and push to queue
-                    match self.push_gainers_to_queue(&gainers, "ALL_TIMEFRAMES", chain).await {
-                        Ok(pushed_count) => {
-                            total_discovered_wallets += pushed_count;
-                            debug!("📤 Pushed {} gainer wallets to analysis queue for chain {}", pushed_count, chain);
-                        }
-                        Err(e) => {
-                            warn!("❌ Failed to push gainers for chain {}: {}", chain, e);
-                        }
-                    }
-                } else {
-                    debug!("⭕ No gainers found across all timeframes for chain {}", chain);
-                }
-            }
-            Err(e) => {
-                warn!("❌ Failed to get gainers for chain {}: {}", chain, e);
-            }
+        if shutdown_token.is_cancelled() {
+            info!("🛑 Stop requested after trending discovery, breaking out of chain cycle");
+            return Ok(total_discovered_wallets);
         }
 
-        // Step 4: Get boosted tokens from DexScreener (NEW DISCOVERY SOURCE)
-        if let Some(ref dexscreener_client) = self.dexscreener_client {
-            info!("🚀 Starting DexScreener boosted token discovery");
-            
-            // Get both latest and top boosted tokens
-            match dexscreener_client.get_all_boosted_tokens().await {
-                Ok((latest_tokens, top_tokens)) => {
-                    // Process latest boosted tokens
-                    if !latest_tokens.is_empty() {
-                        info!("📈 Found {} latest boosted tokens", latest_tokens.len());
-                        match self.process_boosted_tokens(&latest_tokens, "latest").await {
+        // Step 3: Get top gainers across different timeframes with pagination for this chain
+        if self.is_source_due("gainers", chain).await {
+            info!("🏆 Starting paginated multi-timeframe gainers discovery for chain: {}", chain);
+
+            match self.get_top_gainers_for_chain(chain).await {
+                Ok(gainers) => {
+                    if !gainers.is_empty() {
+                        info!("💰 Found {} top gainers across all timeframes for chain {}", gainers.len(), chain);
+
+                        // Convert gainers to wallet-token pairs and push to queue
+                        match self.push_gainers_to_queue(&gainers, "ALL_TIMEFRAMES", chain).await {
                             Ok(pushed_count) => {
                                 total_discovered_wallets += pushed_count;
-                                debug!("📤 Pushed {} wallets from latest boosted tokens", pushed_count);
+                                debug!("📤 Pushed {} gainer wallets to analysis queue for chain {}", pushed_count, chain);
                             }
                             Err(e) => {
-                                warn!("❌ Failed to process latest boosted tokens: {}", e);
+                                warn!("❌ Failed to push gainers for chain {}: {}", chain, e);
                             }
                         }
+                    } else {
+                        debug!("⭕ No gainers found across all timeframes for chain {}", chain);
                     }
-                    
-                    // Process top boosted tokens
-                    if !top_tokens.is_empty() {
-                        info!("🏆 Found {} top boosted tokens", top_tokens.len());
-                        match self.process_boosted_tokens(&top_tokens, "top").await {
-                            Ok(pushed_count) => {
-                                total_discovered_wallets += pushed_count;
-                                debug!("📤 Pushed {} wallets from top boosted tokens", pushed_count);
+                }
+                Err(e) => {
+                    warn!("❌ Failed to get gainers for chain {}: {}", chain, e);
+                }
+            }
+            self.mark_source_ran("gainers", chain).await;
+        } else {
+            debug!("⏱️ Gainers discovery not due yet for chain {}", chain);
+        }
+
+        // Step 4: Get boosted tokens from DexScreener (NEW DISCOVERY SOURCE)
+        if self.is_source_due("boosted", chain).await {
+            if let Some(ref dexscreener_client) = self.dexscreener_client {
+                info!("🚀 Starting DexScreener boosted token discovery");
+
+                // Get both latest and top boosted tokens
+                let call_started = Instant::now();
+                let call_result = dexscreener_client.get_all_boosted_tokens().await;
+                self.metrics.observe_latency("get_all_boosted_tokens", call_started.elapsed()).await;
+
+                match call_result {
+                    Ok((latest_tokens, top_tokens)) => {
+                        self.metrics.add_tokens_fetched("boosted", (latest_tokens.len() + top_tokens.len()) as u64).await;
+                        // Process latest boosted tokens
+                        if !latest_tokens.is_empty() {
+                            info!("📈 Found {} latest boosted tokens", latest_tokens.len());
+                            match self.process_boosted_tokens(&latest_tokens, "latest", shutdown_token).await {
+                                Ok(pushed_count) => {
+                                    total_discovered_wallets += pushed_count;
+                                    debug!("📤 Pushed {} wallets from latest boosted tokens", pushed_count);
+                                }
+                                Err(e) => {
+                                    warn!("❌ Failed to process latest boosted tokens: {}", e);
+                                }
                             }
-                            Err(e) => {
-                                warn!("❌ Failed to process top boosted tokens: {}", e);
+                        }
+
+                        // Process top boosted tokens
+                        if !top_tokens.is_empty() {
+                            info!("🏆 Found {} top boosted tokens", top_tokens.len());
+                            match self.process_boosted_tokens(&top_tokens, "top", shutdown_token).await {
+                                Ok(pushed_count) => {
+                                    total_discovered_wallets += pushed_count;
+                                    debug!("📤 Pushed {} wallets from top boosted tokens", pushed_count);
+                                }
+                                Err(e) => {
+                                    warn!("❌ Failed to process top boosted tokens: {}", e);
+                                }
                             }
                         }
                     }
+                    Err(e) => {
+                        warn!("❌ Failed to fetch boosted tokens from DexScreener: {}", e);
+                    }
                 }
-                Err(e) => {
-                    warn!("❌ Failed to fetch boosted tokens from DexScreener: {}", e);
-                }
+            } else {
+                debug!("⭕ DexScreener client disabled, skipping boosted token discovery");
             }
+            self.mark_source_ran("boosted", chain).await;
         } else {
-            debug!("⭕ DexScreener client disabled, skipping boosted token discovery");
+            debug!("⏱️ Boosted discovery not due yet for chain {}", chain);
         }
 
         // Step 5: Get newly listed tokens (NEW DISCOVERY SOURCE) for this chain
-        if self.config.birdeye.new_listing_enabled {
+        if self.config.birdeye.new_listing_enabled && self.is_source_due("new_listing", chain).await {
             info!("🆕 Starting new listing token discovery for chain: {}", chain);
-            
+
             match self.get_new_listing_tokens_for_chain(chain).await {
                 Ok(new_listing_tokens) => {
                     if !new_listing_tokens.is_empty() {
                         info!("📈 Found {} new listing tokens for chain {}", new_listing_tokens.len(), chain);
-                        
-                        match self.process_new_listing_tokens(&new_listing_tokens).await {
+
+                        match self.process_new_listing_tokens(&new_listing_tokens, shutdown_token).await {
                             Ok(pushed_count) => {
                                 total_discovered_wallets += pushed_count;
                                 debug!("📤 Pushed {} wallets from new listing tokens for chain {}", pushed_count, chain);
@@ -346,12 +585,40 @@ impl BirdEyeTrendingOrchestrator {
                     warn!("❌ Failed to fetch new listing tokens for chain {}: {}", chain, e);
                 }
             }
-        } else {
+            self.mark_source_ran("new_listing", chain).await;
+        } else if !self.config.birdeye.new_listing_enabled {
             debug!("⭕ New listing token discovery disabled for chain {}", chain);
+        } else {
+            debug!("⏱️ New listing discovery not due yet for chain {}", chain);
+        }
+
+        // Step 6: Re-evaluate pending price triggers for tokens parked by register_price_trigger,
+        // on its own "price_trigger" schedule (falls back to `schedule_for`'s default 60s
+        // interval like any other unlisted source) so the now-15s outer loop tick doesn't
+        // re-fetch `get_token_overview` per pending trigger far more often than intended.
+        if self.config.birdeye.price_trigger_enabled {
+            if self.is_source_due("price_trigger", chain).await {
+                match self.check_price_triggers(chain).await {
+                    Ok(pushed_count) => {
+                        if pushed_count > 0 {
+                            total_discovered_wallets += pushed_count;
+                            debug!("📤 Pushed {} wallets from crossed price triggers for chain {}", pushed_count, chain);
+                        }
+                    }
+                    Err(e) => {
+                        warn!("❌ Failed to check price triggers for chain {}: {}", chain, e);
+                    }
+                }
+                self.mark_source_ran("price_trigger", chain).await;
+            } else {
+                debug!("⏱️ Price trigger re-evaluation not due yet for chain {}", chain);
+            }
+        } else {
+            debug!("⭕ Price trigger re-evaluation disabled for chain {}", chain);
         }
 
         info!("✅ Enhanced Multi-Source Discovery Cycle Completed for chain {}: {} total quality wallets discovered", chain, total_discovered_wallets);
-        debug!("📊 Discovery breakdown for chain {}: Paginated trending (unlimited tokens, 3 sorts × 5 pages = 15 calls) → paginated top traders (5x) | Paginated gainers (3 timeframes × 5 pages = 15 calls) → direct wallets | DexScreener boosted → paginated top traders (5x) | New listing tokens → paginated top traders (5x)", chain);
+        debug!("📊 Discovery breakdown for chain {}: Paginated trending (unlimited tokens, 3 sorts × 5 pages = 15 calls) → paginated top traders (5x) | Paginated gainers (3 timeframes × 5 pages = 15 calls) → direct wallets | DexScreener boosted → paginated top traders (5x) | New listing tokens → paginated top traders (5x) | Pending price triggers → paginated top traders", chain);
         Ok(total_discovered_wallets)
     }
 
@@ -359,9 +626,14 @@ impl BirdEyeTrendingOrchestrator {
     async fn get_top_gainers_for_chain(&self, chain: &str) -> Result<Vec<GainerLoser>> {
         debug!("💰 Fetching top gainers across all timeframes with pagination for chain: {}", chain);
 
-        match self.birdeye_client.get_gainers_losers_paginated(chain).await {
+        let call_started = Instant::now();
+        let call_result = self.birdeye_client.get_gainers_losers_paginated(chain).await;
+        self.metrics.observe_latency("get_top_gainers_for_chain", call_started.elapsed()).await;
+
+        match call_result {
             Ok(gainers) => {
                 debug!("📊 Retrieved {} gainers across all timeframes and pages for chain {}", gainers.len(), chain);
+                self.metrics.add_tokens_fetched("gainers", gainers.len() as u64).await;
                 Ok(gainers)
             }
             Err(e) => {
@@ -391,19 +663,30 @@ impl BirdEyeTrendingOrchestrator {
             })
             .collect();
 
-        debug!("📤 Pushing {} gainer wallet-token pairs to Redis queue for timeframe {} on chain {}", 
-               wallet_token_pairs.len(), timeframe, chain);
+        let unseen_pairs = self.dedup_cache.filter_unseen(&wallet_token_pairs).await;
+        let (cache_hits, _) = self.dedup_cache.hit_miss_counts();
+        self.metrics.add_duplicates_skipped("gainers", (wallet_token_pairs.len() - unseen_pairs.len()) as u64).await;
+        debug!("📤 Pushing {} gainer wallet-token pairs ({} already in LRU cache) to Redis queue for timeframe {} on chain {} (cache hits so far: {})",
+               unseen_pairs.len(), wallet_token_pairs.len() - unseen_pairs.len(), timeframe, chain, cache_hits);
+
+        if unseen_pairs.is_empty() {
+            return Ok(0);
+        }
 
         let redis = self.redis_client.lock().await;
         if let Some(ref redis_client) = *redis {
-            match redis_client.push_discovered_wallet_token_pairs_deduplicated(&wallet_token_pairs).await {
+            match redis_client.push_discovered_wallet_token_pairs_deduplicated(&unseen_pairs).await {
                 Ok(pushed_count) => {
-                    let skipped_count = wallet_token_pairs.len() - pushed_count;
+                    drop(redis);
+                    self.dedup_cache.record_pushed(&unseen_pairs).await;
+                    self.metrics.add_wallets_pushed("gainers", pushed_count as u64).await;
+                    self.emit_discovery_event(DiscoveryEventKind::PairsEnqueued, DiscoverySource::Gainer, chain, "ALL_TOKENS", &format!("GAINER_{}", timeframe.to_uppercase()), pushed_count);
+                    let skipped_count = unseen_pairs.len() - pushed_count;
                     if skipped_count > 0 {
-                        info!("✅ Pushed {} new gainer wallet-token pairs for {} on chain {} (skipped {} duplicates)", 
+                        info!("✅ Pushed {} new gainer wallet-token pairs for {} on chain {} (skipped {} duplicates)",
                               pushed_count, timeframe, chain, skipped_count);
                     } else {
-                        info!("✅ Successfully pushed {} gainer wallet-token pairs for {} on chain {}", 
+                        info!("✅ Successfully pushed {} gainer wallet-token pairs for {} on chain {}",
                               pushed_count, timeframe, chain);
                     }
                     Ok(pushed_count)
@@ -420,7 +703,7 @@ impl BirdEyeTrendingOrchestrator {
     }
 
     /// Process boosted tokens from DexScreener and get top traders for each
-    async fn process_boosted_tokens(&self, boosted_tokens: &[DexScreenerBoostedToken], source: &str) -> Result<usize> {
+    async fn process_boosted_tokens(&self, boosted_tokens: &[DexScreenerBoostedToken], source: &str, shutdown_token: &CancellationToken) -> Result<usize> {
         if boosted_tokens.is_empty() {
             return Ok(0);
         }
@@ -446,30 +729,33 @@ impl BirdEyeTrendingOrchestrator {
         // For each boosted token, get top traders using BirdEye
         for (i, boosted_token) in processed_tokens.iter().enumerate() {
             // Check if we should stop before processing each boosted token
-            {
-                let is_running = self.is_running.lock().await;
-                if !*is_running {
-                    info!("🛑 Stop requested during boosted token processing, breaking out of loop at token {}/{}", 
-                          i + 1, processed_tokens.len());
-                    break;
-                }
+            if shutdown_token.is_cancelled() {
+                info!("🛑 Stop requested during boosted token processing, breaking out of loop at token {}/{}",
+                      i + 1, processed_tokens.len());
+                break;
             }
 
-            debug!("🎯 Processing boosted token {}/{}: {}", 
+            debug!("🎯 Processing boosted token {}/{}: {}",
                    i + 1, processed_tokens.len(), boosted_token.token_address);
 
+            // Let the streaming logsSubscribe task surface real-time swaps on this token too.
+            self.watched_tokens.watch(&boosted_token.token_address).await;
+            let boosted_symbol = format!("BOOSTED_{}", source.to_uppercase());
+            self.emit_discovery_event(DiscoveryEventKind::TokenDiscovered, DiscoverySource::Boosted, &boosted_token.chain_id, &boosted_token.token_address, &boosted_symbol, 0);
+
             // Use the chain from the boosted token
             let boosted_chain = &boosted_token.chain_id;
             match self.get_top_traders_for_token(&boosted_token.token_address, boosted_chain).await {
                 Ok(top_traders) => {
                     if !top_traders.is_empty() {
-                        info!("👤 Found {} quality traders for boosted token {} ({})", 
+                        info!("👤 Found {} quality traders for boosted token {} ({})",
                               top_traders.len(), boosted_token.token_address, source);
+                        self.emit_discovery_event(DiscoveryEventKind::TradersFound, DiscoverySource::Boosted, boosted_chain, &boosted_token.token_address, &boosted_symbol, top_traders.len());
 
                         // Create a synthetic "trending token" structure for boosted tokens
                         let synthetic_token = BirdEyeTrendingToken {
                             address: boosted_token.token_address.clone(),
-                            symbol: format!("BOOSTED_{}", source.to_uppercase()),
+                            symbol: boosted_symbol.clone(),
                             name: boosted_token.description.clone().unwrap_or_else(|| "Boosted Token".to_string()),
                             decimals: None,
                             price: 0.0, // Default price for boosted tokens
@@ -486,7 +772,7 @@ impl BirdEyeTrendingOrchestrator {
                         };
 
                         // Push quality wallet-token pairs to Redis for P&L analysis
-                        match self.push_wallet_token_pairs_to_queue(&top_traders, &synthetic_token, boosted_chain).await {
+                        match self.push_wallet_token_pairs_to_queue(&top_traders, &synthetic_token, boosted_chain, DiscoverySource::Boosted).await {
                             Ok(pushed_count) => {
                                 total_discovered_wallets += pushed_count;
                                 debug!("📤 Pushed {} wallets to analysis queue for boosted token {}", 
@@ -509,27 +795,17 @@ impl BirdEyeTrendingOrchestrator {
 
             // Rate limiting between boosted tokens (interruptible)
             if i < processed_tokens.len() - 1 {
-                // Make this sleep interruptible by checking stop flag every 100ms
-                let sleep_duration = Duration::from_millis(500);
-                let check_interval = Duration::from_millis(100);
-                let start_time = std::time::Instant::now();
-                
-                while start_time.elapsed() < sleep_duration {
-                    tokio::time::sleep(check_interval).await;
-                    
-                    // Check if we should stop during rate limiting sleep
-                    {
-                        let is_running = self.is_running.lock().await;
-                        if !*is_running {
-                            info!("🛑 Stop requested during boosted token rate limiting, breaking out early");
-                            return Ok(total_discovered_wallets);
-                        }
+                tokio::select! {
+                    _ = shutdown_token.cancelled() => {
+                        info!("🛑 Stop requested during boosted token rate limiting, breaking out early");
+                        return Ok(total_discovered_wallets);
                     }
+                    _ = tokio::time::sleep(Duration::from_millis(500)) => {}
                 }
             }
         }
 
-        debug!("✅ Boosted token processing completed: {} total wallets discovered from {}", 
+        debug!("✅ Boosted token processing completed: {} total wallets discovered from {}",
                total_discovered_wallets, source);
         Ok(total_discovered_wallets)
     }
@@ -538,10 +814,15 @@ impl BirdEyeTrendingOrchestrator {
     async fn get_trending_tokens_for_chain(&self, chain: &str) -> Result<Vec<BirdEyeTrendingToken>> {
         debug!("📊 Starting paginated trending token discovery from BirdEye for chain: {}", chain);
 
-        match self.birdeye_client.get_trending_tokens_paginated(chain).await {
+        let call_started = Instant::now();
+        let call_result = self.birdeye_client.get_trending_tokens_paginated(chain).await;
+        self.metrics.observe_latency("get_trending_tokens_for_chain", call_started.elapsed()).await;
+
+        match call_result {
             Ok(mut tokens) => {
                 info!("🎯 Paginated discovery completed: {} unique tokens found across all pages for chain {}", tokens.len(), chain);
-                
+                self.metrics.add_tokens_fetched("trending", tokens.len() as u64).await;
+
                 // Apply volume-based sorting (already done in multi-sort method but ensure consistency)
                 tokens.sort_by(|a, b| b.volume_24h.partial_cmp(&a.volume_24h).unwrap_or(std::cmp::Ordering::Equal));
                 
@@ -590,7 +871,11 @@ impl BirdEyeTrendingOrchestrator {
     async fn get_top_traders_for_token(&self, token_address: &str, chain: &str) -> Result<Vec<TopTrader>> {
         debug!("👥 Fetching top traders for token: {} on chain: {}", token_address, chain);
 
-        match self.birdeye_client.get_top_traders_paginated(token_address, chain).await {
+        let call_started = Instant::now();
+        let call_result = self.birdeye_client.get_top_traders_paginated(token_address, chain).await;
+        self.metrics.observe_latency("get_top_traders_for_token", call_started.elapsed()).await;
+
+        match call_result {
             Ok(traders) => {
                 debug!("📊 Retrieved {} raw traders for token {} on chain {}", traders.len(), token_address, chain);
 
@@ -619,6 +904,7 @@ impl BirdEyeTrendingOrchestrator {
                     }
                 }
 
+                self.metrics.add_traders_found("top_traders", filtered_traders.len() as u64).await;
                 Ok(filtered_traders)
             }
             Err(e) => {
@@ -628,12 +914,73 @@ impl BirdEyeTrendingOrchestrator {
         }
     }
 
+    /// Whether `token_address`'s realizable order-book depth is deep enough to trust its
+    /// reported `liquidity`, modeled on the bid/ask book structure in Solana's bench-exchange
+    /// `order_book` module: fetch BirdEye's order book, sum USD volume within
+    /// `trader_filter.depth_slippage_band_ratio` of mid-price on each side, and compare the
+    /// smaller side (what a trader could actually realize exiting) against reported liquidity.
+    /// Tokens nobody can actually trade out of inflate `trader_volume_usd` without real exit
+    /// capacity, wasting P&L-analysis budget downstream. Fails open (returns `true`) when the
+    /// gate is disabled, there's no reported liquidity to compare against, or the depth fetch
+    /// itself errors — this is a quality filter, not a correctness one.
+    async fn passes_depth_check(&self, token_address: &str, chain: &str, reported_liquidity: Option<f64>) -> bool {
+        if !self.config.birdeye.order_book_depth_check_enabled {
+            return true;
+        }
+
+        let reported_liquidity = match reported_liquidity {
+            Some(liquidity) if liquidity > 0.0 => liquidity,
+            _ => return true,
+        };
+
+        let call_started = Instant::now();
+        let call_result = self.birdeye_client
+            .get_order_book_depth_usd(token_address, chain, self.config.trader_filter.depth_slippage_band_ratio)
+            .await;
+        self.metrics.observe_latency("get_order_book_depth_usd", call_started.elapsed()).await;
+
+        let (bid_depth_usd, ask_depth_usd) = match call_result {
+            Ok(depth) => depth,
+            Err(e) => {
+                warn!("⚠️ Failed to fetch order-book depth for {} on chain {}: {} — skipping depth gate", token_address, chain, e);
+                return true;
+            }
+        };
+
+        let realizable_usd = bid_depth_usd.min(ask_depth_usd);
+        let depth_ratio = realizable_usd / reported_liquidity;
+
+        // A NaN/infinite ratio (garbage depth from upstream) compares `false` against the
+        // threshold either way round, which would silently pass the gate instead of failing
+        // it - reject explicitly so bad data doesn't masquerade as deep liquidity.
+        if !depth_ratio.is_finite() {
+            warn!("⚠️ Non-finite depth ratio for {} on chain {} (bid=${:.0}, ask=${:.0}, liquidity=${:.0}) — rejecting", token_address, chain, bid_depth_usd, ask_depth_usd, reported_liquidity);
+            self.metrics.add_depth_rejected(chain, 1).await;
+            return false;
+        }
+
+        if depth_ratio < self.config.trader_filter.min_depth_to_liquidity_ratio {
+            warn!("⛔ Rejecting {} on chain {}: realizable depth ${:.0} is only {:.1}% of reported liquidity ${:.0} (min {:.1}%)",
+                  token_address, chain, realizable_usd, depth_ratio * 100.0, reported_liquidity,
+                  self.config.trader_filter.min_depth_to_liquidity_ratio * 100.0);
+            self.metrics.add_depth_rejected(chain, 1).await;
+            false
+        } else {
+            true
+        }
+    }
+
     /// Push quality wallet-token pairs to Redis queue for targeted P&L analysis
-    async fn push_wallet_token_pairs_to_queue(&self, traders: &[TopTrader], token: &BirdEyeTrendingToken, chain: &str) -> Result<usize> {
+    async fn push_wallet_token_pairs_to_queue(&self, traders: &[TopTrader], token: &BirdEyeTrendingToken, chain: &str, source: DiscoverySource) -> Result<usize> {
         if traders.is_empty() {
             return Ok(0);
         }
 
+        if !self.passes_depth_check(&token.address, chain, token.liquidity).await {
+            debug!("⭕ Skipping {} on chain {}: failed order-book depth check", token.symbol, chain);
+            return Ok(0);
+        }
+
         let wallet_token_pairs: Vec<DiscoveredWalletToken> = traders.iter()
             .map(|trader| DiscoveredWalletToken {
                 wallet_address: trader.owner.clone(),
@@ -646,18 +993,40 @@ impl BirdEyeTrendingOrchestrator {
             })
             .collect();
 
-        debug!("📤 Pushing {} wallet-token pairs to Redis queue for token {} on chain {}", wallet_token_pairs.len(), token.symbol, chain);
+        let source_label = match source {
+            DiscoverySource::Trending => "trending",
+            DiscoverySource::Gainer => "gainers",
+            DiscoverySource::Boosted => "boosted",
+            DiscoverySource::NewListing => "new_listing",
+            DiscoverySource::Streaming => "streaming",
+            DiscoverySource::Cycle => "cycle",
+            DiscoverySource::Triggered => "triggered",
+        };
+
+        let unseen_pairs = self.dedup_cache.filter_unseen(&wallet_token_pairs).await;
+        let (cache_hits, _) = self.dedup_cache.hit_miss_counts();
+        self.metrics.add_duplicates_skipped(source_label, (wallet_token_pairs.len() - unseen_pairs.len()) as u64).await;
+        debug!("📤 Pushing {} wallet-token pairs ({} already in LRU cache) to Redis queue for token {} on chain {} (cache hits so far: {})",
+               unseen_pairs.len(), wallet_token_pairs.len() - unseen_pairs.len(), token.symbol, chain, cache_hits);
+
+        if unseen_pairs.is_empty() {
+            return Ok(0);
+        }
 
         let redis = self.redis_client.lock().await;
         if let Some(ref redis_client) = *redis {
-            match redis_client.push_discovered_wallet_token_pairs_deduplicated(&wallet_token_pairs).await {
+            match redis_client.push_discovered_wallet_token_pairs_deduplicated(&unseen_pairs).await {
                 Ok(pushed_count) => {
-                    let skipped_count = wallet_token_pairs.len() - pushed_count;
+                    drop(redis);
+                    self.dedup_cache.record_pushed(&unseen_pairs).await;
+                    self.metrics.add_wallets_pushed(source_label, pushed_count as u64).await;
+                    self.emit_discovery_event(DiscoveryEventKind::PairsEnqueued, source, chain, &token.address, &token.symbol, pushed_count);
+                    let skipped_count = unseen_pairs.len() - pushed_count;
                     if skipped_count > 0 {
-                        info!("✅ Pushed {} new wallet-token pairs to analysis queue for {} on chain {} (skipped {} duplicates)", 
+                        info!("✅ Pushed {} new wallet-token pairs to analysis queue for {} on chain {} (skipped {} duplicates)",
                               pushed_count, token.symbol, chain, skipped_count);
                     } else {
-                        info!("✅ Successfully pushed {} quality wallet-token pairs to analysis queue for {} on chain {}", 
+                        info!("✅ Successfully pushed {} quality wallet-token pairs to analysis queue for {} on chain {}",
                               pushed_count, token.symbol, chain);
                     }
                     Ok(pushed_count)
@@ -678,9 +1047,12 @@ impl BirdEyeTrendingOrchestrator {
     /// Get new listing tokens with comprehensive coverage for a specific chain
     async fn get_new_listing_tokens_for_chain(&self, chain: &str) -> Result<Vec<NewListingToken>> {
         debug!("🆕 Fetching new listing tokens with comprehensive coverage for chain: {}", chain);
-        
+
+        let call_started = Instant::now();
         let all_tokens = self.birdeye_client.get_new_listing_tokens_comprehensive(chain).await?;
-        
+        self.metrics.observe_latency("get_new_listing_tokens_comprehensive", call_started.elapsed()).await;
+        self.metrics.add_tokens_fetched("new_listing", all_tokens.len() as u64).await;
+
         // Apply quality filtering
         let filter = NewListingTokenFilter {
             min_liquidity: Some(self.config.birdeye.new_listing_min_liquidity),
@@ -706,37 +1078,39 @@ impl BirdEyeTrendingOrchestrator {
 
 
     /// Process new listing tokens and get top traders for each
-    async fn process_new_listing_tokens(&self, new_listing_tokens: &[NewListingToken]) -> Result<usize> {
+    async fn process_new_listing_tokens(&self, new_listing_tokens: &[NewListingToken], shutdown_token: &CancellationToken) -> Result<usize> {
         if new_listing_tokens.is_empty() {
             return Ok(0);
         }
-        
+
         debug!("🔄 Processing {} new listing tokens", new_listing_tokens.len());
-        
+
         let mut total_discovered_wallets = 0;
-        
+
         for (i, token) in new_listing_tokens.iter().enumerate() {
             // Check if we should stop before processing each new listing token
-            {
-                let is_running = self.is_running.lock().await;
-                if !*is_running {
-                    info!("🛑 Stop requested during new listing token processing, breaking out of loop at token {}/{}", 
-                          i + 1, new_listing_tokens.len());
-                    break;
-                }
+            if shutdown_token.is_cancelled() {
+                info!("🛑 Stop requested during new listing token processing, breaking out of loop at token {}/{}",
+                      i + 1, new_listing_tokens.len());
+                break;
             }
 
-            debug!("🎯 Processing new listing token {}/{}: {} ({})", 
+            debug!("🎯 Processing new listing token {}/{}: {} ({})",
                    i + 1, new_listing_tokens.len(), token.symbol, token.address);
-            
+
+            // Let the streaming logsSubscribe task surface real-time swaps on this token too.
+            self.watched_tokens.watch(&token.address).await;
+
             // Use default chain for new listing tokens
             let listing_chain = &self.config.multichain.default_chain;
+            self.emit_discovery_event(DiscoveryEventKind::TokenDiscovered, DiscoverySource::NewListing, listing_chain, &token.address, &token.symbol, 0);
             match self.get_top_traders_for_token(&token.address, listing_chain).await {
                 Ok(top_traders) => {
                     if !top_traders.is_empty() {
-                        info!("👤 Found {} quality traders for new listing token {} ({})", 
+                        info!("👤 Found {} quality traders for new listing token {} ({})",
                               top_traders.len(), token.symbol, token.address);
-                        
+                        self.emit_discovery_event(DiscoveryEventKind::TradersFound, DiscoverySource::NewListing, listing_chain, &token.address, &token.symbol, top_traders.len());
+
                         // Convert NewListingToken to TrendingToken format for compatibility
                         let synthetic_trending_token = BirdEyeTrendingToken {
                             address: token.address.clone(),
@@ -757,7 +1131,7 @@ impl BirdEyeTrendingOrchestrator {
                         };
                         
                         // Use existing wallet-token pair pushing logic
-                        match self.push_wallet_token_pairs_to_queue(&top_traders, &synthetic_trending_token, listing_chain).await {
+                        match self.push_wallet_token_pairs_to_queue(&top_traders, &synthetic_trending_token, listing_chain, DiscoverySource::NewListing).await {
                             Ok(pushed_count) => {
                                 total_discovered_wallets += pushed_count;
                                 debug!("📤 Pushed {} wallets for new listing token {}", pushed_count, token.symbol);
@@ -777,36 +1151,113 @@ impl BirdEyeTrendingOrchestrator {
             
             // Rate limiting between tokens (interruptible)
             if i < new_listing_tokens.len() - 1 {
-                // Make this sleep interruptible by checking stop flag every 100ms
-                let sleep_duration = Duration::from_millis(500);
-                let check_interval = Duration::from_millis(100);
-                let start_time = std::time::Instant::now();
-                
-                while start_time.elapsed() < sleep_duration {
-                    tokio::time::sleep(check_interval).await;
-                    
-                    // Check if we should stop during rate limiting sleep
-                    {
-                        let is_running = self.is_running.lock().await;
-                        if !*is_running {
-                            info!("🛑 Stop requested during new listing token rate limiting, breaking out early");
-                            return Ok(total_discovered_wallets);
-                        }
+                tokio::select! {
+                    _ = shutdown_token.cancelled() => {
+                        info!("🛑 Stop requested during new listing token rate limiting, breaking out early");
+                        return Ok(total_discovered_wallets);
                     }
+                    _ = tokio::time::sleep(Duration::from_millis(500)) => {}
                 }
             }
         }
-        
+
         info!("✅ New listing token processing completed: {} total wallets discovered", total_discovered_wallets);
         Ok(total_discovered_wallets)
     }
 
+    /// Register a token for deferred re-evaluation instead of discarding it: `check_price_triggers`
+    /// will fetch its top traders and enqueue them once `trigger.metric` crosses `trigger.target`
+    /// in `trigger.direction`. Triggers are persisted in Redis to survive restarts, so this
+    /// errors out when Redis isn't configured rather than silently dropping the trigger - a
+    /// caller that thinks it registered one would otherwise never see it fire.
+    pub async fn register_price_trigger(&self, trigger: PriceTrigger) -> Result<()> {
+        let redis = self.redis_client.lock().await;
+        if let Some(ref redis_client) = *redis {
+            redis_client.set_price_trigger(&trigger).await?;
+            info!("📌 Registered price trigger for {} ({}): {:?} {:?} {}",
+                  trigger.token_symbol, trigger.token_address, trigger.metric, trigger.direction, trigger.target);
+            Ok(())
+        } else {
+            anyhow::bail!("Redis client not available, cannot persist price trigger for {}", trigger.token_address);
+        }
+    }
+
+    /// Re-evaluate every pending `PriceTrigger` for `chain` against freshly fetched BirdEye
+    /// data, firing `get_top_traders_for_token` + `push_wallet_token_pairs_to_queue` for any
+    /// that crossed and removing them from Redis. Triggers that haven't crossed yet, or whose
+    /// fresh data fails to fetch, are left pending for the next cycle.
+    async fn check_price_triggers(&self, chain: &str) -> Result<usize> {
+        let pending = {
+            let redis = self.redis_client.lock().await;
+            if let Some(ref redis_client) = *redis {
+                redis_client.get_price_triggers(chain).await.unwrap_or_default()
+            } else {
+                return Ok(0);
+            }
+        };
+
+        if pending.is_empty() {
+            return Ok(0);
+        }
+
+        debug!("👀 Re-evaluating {} pending price triggers for chain {}", pending.len(), chain);
+
+        let mut total_discovered_wallets = 0;
+
+        for trigger in &pending {
+            let call_started = Instant::now();
+            let overview = self.birdeye_client.get_token_overview(&trigger.token_address, chain).await;
+            self.metrics.observe_latency("get_token_overview", call_started.elapsed()).await;
+
+            let token = match overview {
+                Ok(token) => token,
+                Err(e) => {
+                    warn!("❌ Failed to fetch overview for triggered token {} on chain {}: {}", trigger.token_address, chain, e);
+                    continue;
+                }
+            };
+
+            let current = match trigger.metric {
+                TriggerMetric::Price => token.price,
+                TriggerMetric::Liquidity => token.liquidity.unwrap_or(0.0),
+            };
+
+            if !trigger.crossed(current) {
+                continue;
+            }
+
+            info!("🎯 Price trigger crossed for {} ({}): {:?} now {} ({:?} {})",
+                  trigger.token_symbol, trigger.token_address, trigger.metric, current, trigger.direction, trigger.target);
+
+            match self.get_top_traders_for_token(&trigger.token_address, chain).await {
+                Ok(top_traders) if !top_traders.is_empty() => {
+                    match self.push_wallet_token_pairs_to_queue(&top_traders, &token, chain, DiscoverySource::Triggered).await {
+                        Ok(pushed_count) => total_discovered_wallets += pushed_count,
+                        Err(e) => warn!("❌ Failed to push wallets for triggered token {}: {}", trigger.token_address, e),
+                    }
+                }
+                Ok(_) => debug!("⭕ No quality traders found for triggered token {}", trigger.token_address),
+                Err(e) => warn!("❌ Failed to get top traders for triggered token {}: {}", trigger.token_address, e),
+            }
+
+            let redis = self.redis_client.lock().await;
+            if let Some(ref redis_client) = *redis {
+                if let Err(e) = redis_client.remove_price_trigger(&trigger.token_address, chain).await {
+                    warn!("❌ Failed to remove fired price trigger for {}: {}", trigger.token_address, e);
+                }
+            }
+        }
+
+        Ok(total_discovered_wallets)
+    }
+
     /// Get statistics about the current discovery state
     pub async fn get_discovery_stats(&self) -> Result<DiscoveryStats> {
         let redis = self.redis_client.lock().await;
         if let Some(ref redis_client) = *redis {
             let queue_size = redis_client.get_wallet_queue_size().await.unwrap_or(0);
-            
+
+            let (dedup_cache_hits, dedup_cache_misses) = self.dedup_cache.hit_miss_counts();
             Ok(DiscoveryStats {
                 is_running: *self.is_running.lock().await,
                 wallet_queue_size: queue_size as u32,
@@ -815,8 +1266,13 @@ impl BirdEyeTrendingOrchestrator {
                 wallet_token_pairs_discovered: queue_size as u32,
                 new_listing_tokens_discovered: 0, // TODO: Track this metric
                 new_listing_wallets_discovered: 0, // TODO: Track this metric
+                dedup_cache_hits,
+                dedup_cache_misses,
+                latency_histograms: self.metrics.latency_snapshot().await,
+                tokens_rejected_shallow_depth: self.metrics.total_depth_rejected().await as u32,
             })
         } else {
+            let (dedup_cache_hits, dedup_cache_misses) = self.dedup_cache.hit_miss_counts();
             Ok(DiscoveryStats {
                 is_running: *self.is_running.lock().await,
                 wallet_queue_size: 0,
@@ -825,11 +1281,782 @@ impl BirdEyeTrendingOrchestrator {
                 wallet_token_pairs_discovered: 0,
                 new_listing_tokens_discovered: 0,
                 new_listing_wallets_discovered: 0,
+                dedup_cache_hits,
+                dedup_cache_misses,
+                latency_histograms: self.metrics.latency_snapshot().await,
+                tokens_rejected_shallow_depth: self.metrics.total_depth_rejected().await as u32,
             })
         }
     }
 }
 
+/// A per-source cadence: either a fixed interval since the last run, or a specific
+/// wall-clock UTC time run once a day (for pinning heavy sweeps to off-peak windows).
+#[derive(Debug, Clone)]
+enum ScheduleSpec {
+    Interval(Duration),
+    DailyAtUtc { hour: u32, minute: u32 },
+}
+
+impl ScheduleSpec {
+    /// Parse a `"HH:MM"` UTC time-of-day string into a `DailyAtUtc` schedule.
+    fn daily_at_utc(spec: &str) -> Option<Self> {
+        let (hour, minute) = spec.split_once(':')?;
+        let hour: u32 = hour.trim().parse().ok()?;
+        let minute: u32 = minute.trim().parse().ok()?;
+        if hour > 23 || minute > 59 {
+            return None;
+        }
+        Some(ScheduleSpec::DailyAtUtc { hour, minute })
+    }
+
+    /// Compute the next fire time strictly after `from`.
+    fn next_fire(&self, from: chrono::DateTime<chrono::Utc>) -> chrono::DateTime<chrono::Utc> {
+        match self {
+            ScheduleSpec::Interval(interval) => {
+                from + chrono::Duration::from_std(*interval).unwrap_or(chrono::Duration::seconds(60))
+            }
+            ScheduleSpec::DailyAtUtc { hour, minute } => {
+                let today = from
+                    .date_naive()
+                    .and_hms_opt(*hour, *minute, 0)
+                    .map(|naive| chrono::DateTime::<chrono::Utc>::from_naive_utc_and_offset(naive, chrono::Utc))
+                    .unwrap_or(from);
+                if today > from {
+                    today
+                } else {
+                    today + chrono::Duration::days(1)
+                }
+            }
+        }
+    }
+}
+
+/// Bounded, TTL'd LRU cache consulted before every Redis push so wallet-token pairs we
+/// already enqueued seconds ago (in this or a prior cycle) are filtered out locally
+/// instead of round-tripping to Redis. Wrapped in a `tokio::sync::Mutex` like the rest
+/// of the orchestrator's shared state rather than a `std::sync::Mutex`, since lookups
+/// happen from async contexts (including the spawned streaming tasks).
+struct DedupCache {
+    ttl: Duration,
+    state: Mutex<DedupCacheState>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+/// Backs both `DedupCache` and `WatchedTokenRegistry`: an `lru::LruCache` gives O(1)
+/// move-to-front on every touch (an intrusive linked list under the hood) instead of the
+/// `VecDeque::iter().position()` linear scan a hand-rolled order list would need, which
+/// matters here since both caches are consulted on every discovery-cycle pair and every
+/// streamed notification.
+struct DedupCacheState {
+    entries: LruCache<String, Instant>,
+}
+
+impl DedupCacheState {
+    fn new(capacity: usize) -> Self {
+        Self {
+            entries: LruCache::new(NonZeroUsize::new(capacity.max(1)).unwrap()),
+        }
+    }
+}
+
+impl DedupCache {
+    fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            ttl,
+            state: Mutex::new(DedupCacheState::new(capacity)),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    fn key_for(pair: &DiscoveredWalletToken) -> String {
+        format!("{}:{}:{}", pair.chain, pair.wallet_address, pair.token_address)
+    }
+
+    /// Returns only the pairs that are not already present (and not expired) in the
+    /// cache. Does not insert anything — callers insert via `record_pushed` once the
+    /// Redis push actually succeeds, so a failed push doesn't get wrongly suppressed later.
+    async fn filter_unseen(&self, pairs: &[DiscoveredWalletToken]) -> Vec<DiscoveredWalletToken> {
+        let mut state = self.state.lock().await;
+        let now = Instant::now();
+        let mut unseen = Vec::with_capacity(pairs.len());
+
+        for pair in pairs {
+            let key = Self::key_for(pair);
+            // `get` is itself the touch - it bumps `key` to the MRU end in O(1), so a key
+            // that's read repeatedly isn't evicted just because it was inserted long ago.
+            let seen = state.entries.get(&key).map(|exp| *exp > now).unwrap_or(false);
+            if seen {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+            } else {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                unseen.push(pair.clone());
+            }
+        }
+
+        unseen
+    }
+
+    /// Record freshly-pushed pairs as seen. `LruCache::put` evicts the least-recently-used
+    /// entry itself once the cache is over capacity, so there's no separate eviction loop.
+    async fn record_pushed(&self, pairs: &[DiscoveredWalletToken]) {
+        if pairs.is_empty() {
+            return;
+        }
+
+        let mut state = self.state.lock().await;
+        let expiry = Instant::now() + self.ttl;
+
+        for pair in pairs {
+            let key = Self::key_for(pair);
+            state.entries.put(key, expiry);
+        }
+    }
+
+    /// Current (hits, misses) counters for logging.
+    fn hit_miss_counts(&self) -> (u64, u64) {
+        (self.hits.load(Ordering::Relaxed), self.misses.load(Ordering::Relaxed))
+    }
+}
+
+/// Bounded, TTL'd set of token addresses surfaced by the polling discovery sources
+/// (trending/boosted/new-listing), consulted by the streaming `logsSubscribe` task so it
+/// only surfaces traders for tokens we already know are worth watching rather than every
+/// swap on the configured DEX programs. Same shape as `DedupCache` for the same reason:
+/// lookups happen from the spawned streaming task as well as `&self` methods.
+struct WatchedTokenRegistry {
+    ttl: Duration,
+    state: Mutex<DedupCacheState>,
+}
+
+impl WatchedTokenRegistry {
+    fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            ttl,
+            state: Mutex::new(DedupCacheState::new(capacity)),
+        }
+    }
+
+    /// Mark `token_address` as worth watching for `ttl`. `LruCache::put` evicts the
+    /// least-recently-watched entry itself once over capacity.
+    async fn watch(&self, token_address: &str) {
+        let mut state = self.state.lock().await;
+        let expiry = Instant::now() + self.ttl;
+        state.entries.put(token_address.to_string(), expiry);
+    }
+
+    /// Whether `token_address` was watched recently enough that its TTL hasn't expired.
+    async fn is_watched(&self, token_address: &str) -> bool {
+        let mut state = self.state.lock().await;
+        state.entries.get(token_address).map(|exp| *exp > Instant::now()).unwrap_or(false)
+    }
+
+    /// Currently unexpired watched token addresses, for scoping the streaming subscription's
+    /// `mentions` filter directly to them instead of an entire DEX program's logs.
+    async fn snapshot(&self) -> Vec<String> {
+        let state = self.state.lock().await;
+        let now = Instant::now();
+        state
+            .entries
+            .iter()
+            .filter(|(_, expiry)| **expiry > now)
+            .map(|(token_address, _)| token_address.clone())
+            .collect()
+    }
+}
+
+/// Fixed power-of-two millisecond bucket bounds shared by every latency/duration histogram,
+/// so upstream API calls and per-chain cycle times are all comparable at a glance.
+const HISTOGRAM_BUCKET_BOUNDS_MS: &[u64] = &[1, 2, 4, 8, 16, 32, 64, 128, 256, 512, 1024, 2048, 4096, 8192, 16384, 32768, 65536];
+
+/// A fixed-bucket exponential histogram (milliseconds) with running sum/count, good enough
+/// to estimate p50/p90/p99 without pulling in a metrics crate.
+struct Histogram {
+    bucket_counts: Vec<AtomicU64>,
+    sum_ms: AtomicU64,
+    count: AtomicU64,
+}
+
+impl Histogram {
+    fn new() -> Self {
+        Self {
+            bucket_counts: (0..=HISTOGRAM_BUCKET_BOUNDS_MS.len()).map(|_| AtomicU64::new(0)).collect(),
+            sum_ms: AtomicU64::new(0),
+            count: AtomicU64::new(0),
+        }
+    }
+
+    fn observe(&self, elapsed: Duration) {
+        let ms = elapsed.as_millis() as u64;
+        let bucket = HISTOGRAM_BUCKET_BOUNDS_MS.iter().position(|&bound| ms <= bound).unwrap_or(HISTOGRAM_BUCKET_BOUNDS_MS.len());
+        self.bucket_counts[bucket].fetch_add(1, Ordering::Relaxed);
+        self.sum_ms.fetch_add(ms, Ordering::Relaxed);
+        self.count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Estimate the `percentile` (0.0-1.0) latency by walking cumulative bucket counts.
+    fn percentile(&self, percentile: f64) -> u64 {
+        let total = self.count.load(Ordering::Relaxed);
+        if total == 0 {
+            return 0;
+        }
+        let target = ((total as f64) * percentile).ceil() as u64;
+        let mut cumulative = 0u64;
+        for (i, bucket) in self.bucket_counts.iter().enumerate() {
+            cumulative += bucket.load(Ordering::Relaxed);
+            if cumulative >= target {
+                return HISTOGRAM_BUCKET_BOUNDS_MS.get(i).copied().unwrap_or(*HISTOGRAM_BUCKET_BOUNDS_MS.last().unwrap());
+            }
+        }
+        *HISTOGRAM_BUCKET_BOUNDS_MS.last().unwrap()
+    }
+
+    fn count(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    fn sum_ms(&self) -> u64 {
+        self.sum_ms.load(Ordering::Relaxed)
+    }
+}
+
+/// Per-source counters for tokens fetched, wallets pushed, and duplicates skipped, plus the
+/// raw data needed to answer p50/p90/p99 for every instrumented call. Traders found are
+/// counted in one combined `top_traders` entry, not broken down by discovery source.
+#[derive(Default)]
+struct MetricsRegistry {
+    tokens_fetched: Mutex<HashMap<String, u64>>,
+    traders_found: Mutex<HashMap<String, u64>>,
+    wallets_pushed: Mutex<HashMap<String, u64>>,
+    duplicates_skipped: Mutex<HashMap<String, u64>>,
+    /// Tokens rejected by `passes_depth_check` for shallow realizable order-book depth,
+    /// keyed by chain.
+    depth_rejected: Mutex<HashMap<String, u64>>,
+    call_latency: Mutex<HashMap<String, Arc<Histogram>>>,
+    cycle_duration: Mutex<HashMap<String, Arc<Histogram>>>,
+}
+
+impl MetricsRegistry {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    async fn bump(counter: &Mutex<HashMap<String, u64>>, key: &str, amount: u64) {
+        if amount == 0 {
+            return;
+        }
+        let mut map = counter.lock().await;
+        *map.entry(key.to_string()).or_insert(0) += amount;
+    }
+
+    async fn add_tokens_fetched(&self, source: &str, amount: u64) {
+        Self::bump(&self.tokens_fetched, source, amount).await;
+    }
+
+    async fn add_traders_found(&self, source: &str, amount: u64) {
+        Self::bump(&self.traders_found, source, amount).await;
+    }
+
+    async fn add_wallets_pushed(&self, source: &str, amount: u64) {
+        Self::bump(&self.wallets_pushed, source, amount).await;
+    }
+
+    async fn add_duplicates_skipped(&self, source: &str, amount: u64) {
+        Self::bump(&self.duplicates_skipped, source, amount).await;
+    }
+
+    async fn add_depth_rejected(&self, chain: &str, amount: u64) {
+        Self::bump(&self.depth_rejected, chain, amount).await;
+    }
+
+    /// Total tokens rejected for shallow depth across all chains, for `DiscoveryStats`.
+    async fn total_depth_rejected(&self) -> u64 {
+        self.depth_rejected.lock().await.values().sum()
+    }
+
+    async fn observe_latency(&self, endpoint: &str, elapsed: Duration) {
+        let mut map = self.call_latency.lock().await;
+        map.entry(endpoint.to_string()).or_insert_with(|| Arc::new(Histogram::new())).observe(elapsed);
+    }
+
+    async fn observe_cycle_duration(&self, chain: &str, elapsed: Duration) {
+        let mut map = self.cycle_duration.lock().await;
+        map.entry(chain.to_string()).or_insert_with(|| Arc::new(Histogram::new())).observe(elapsed);
+    }
+
+    /// Snapshot every call-latency and cycle-duration histogram's quantiles, labeled
+    /// `call:<endpoint>` / `cycle:<chain>` so `DiscoveryStats` consumers can tell the two
+    /// apart without a separate field per kind.
+    async fn latency_snapshot(&self) -> Vec<LatencyHistogramSnapshot> {
+        let mut snapshots = Vec::new();
+
+        for (endpoint, histogram) in self.call_latency.lock().await.iter() {
+            snapshots.push(LatencyHistogramSnapshot {
+                label: format!("call:{}", endpoint),
+                p50_ms: histogram.percentile(0.5),
+                p90_ms: histogram.percentile(0.9),
+                p99_ms: histogram.percentile(0.99),
+                count: histogram.count(),
+            });
+        }
+
+        for (chain, histogram) in self.cycle_duration.lock().await.iter() {
+            snapshots.push(LatencyHistogramSnapshot {
+                label: format!("cycle:{}", chain),
+                p50_ms: histogram.percentile(0.5),
+                p90_ms: histogram.percentile(0.9),
+                p99_ms: histogram.percentile(0.99),
+                count: histogram.count(),
+            });
+        }
+
+        snapshots
+    }
+
+    /// Render every counter and histogram in Prometheus text exposition format.
+    async fn render_prometheus(&self) -> String {
+        let mut out = String::new();
+
+        out.push_str("# HELP discovery_tokens_fetched_total Tokens fetched per discovery source\n");
+        out.push_str("# TYPE discovery_tokens_fetched_total counter\n");
+        for (source, value) in self.tokens_fetched.lock().await.iter() {
+            out.push_str(&format!("discovery_tokens_fetched_total{{source=\"{}\"}} {}\n", source, value));
+        }
+
+        out.push_str("# HELP discovery_traders_found_total Quality traders found by top-trader lookups, all discovery sources combined\n");
+        out.push_str("# TYPE discovery_traders_found_total counter\n");
+        for (source, value) in self.traders_found.lock().await.iter() {
+            out.push_str(&format!("discovery_traders_found_total{{source=\"{}\"}} {}\n", source, value));
+        }
+
+        out.push_str("# HELP discovery_wallets_pushed_total Wallet-token pairs pushed to Redis per discovery source\n");
+        out.push_str("# TYPE discovery_wallets_pushed_total counter\n");
+        for (source, value) in self.wallets_pushed.lock().await.iter() {
+            out.push_str(&format!("discovery_wallets_pushed_total{{source=\"{}\"}} {}\n", source, value));
+        }
+
+        out.push_str("# HELP discovery_duplicates_skipped_total Wallet-token pairs skipped as duplicates per discovery source\n");
+        out.push_str("# TYPE discovery_duplicates_skipped_total counter\n");
+        for (source, value) in self.duplicates_skipped.lock().await.iter() {
+            out.push_str(&format!("discovery_duplicates_skipped_total{{source=\"{}\"}} {}\n", source, value));
+        }
+
+        out.push_str("# HELP discovery_depth_rejected_total Tokens rejected for shallow realizable order-book depth per chain\n");
+        out.push_str("# TYPE discovery_depth_rejected_total counter\n");
+        for (chain, value) in self.depth_rejected.lock().await.iter() {
+            out.push_str(&format!("discovery_depth_rejected_total{{chain=\"{}\"}} {}\n", chain, value));
+        }
+
+        // Exposed as `quantile=` labels for dashboard convenience, but these are point-in-time
+        // percentiles of an in-process histogram with no decay, not a real Prometheus summary
+        // (which implies a client-side sliding window) - typed `gauge` so scrapers don't assume
+        // summary semantics they don't get.
+        out.push_str("# HELP discovery_upstream_call_latency_ms Upstream API call latency in milliseconds\n");
+        out.push_str("# TYPE discovery_upstream_call_latency_ms gauge\n");
+        for (endpoint, histogram) in self.call_latency.lock().await.iter() {
+            out.push_str(&format!(
+                "discovery_upstream_call_latency_ms{{endpoint=\"{}\",quantile=\"0.5\"}} {}\n",
+                endpoint, histogram.percentile(0.5)
+            ));
+            out.push_str(&format!(
+                "discovery_upstream_call_latency_ms{{endpoint=\"{}\",quantile=\"0.9\"}} {}\n",
+                endpoint, histogram.percentile(0.9)
+            ));
+            out.push_str(&format!(
+                "discovery_upstream_call_latency_ms{{endpoint=\"{}\",quantile=\"0.99\"}} {}\n",
+                endpoint, histogram.percentile(0.99)
+            ));
+            out.push_str(&format!("discovery_upstream_call_latency_ms_sum{{endpoint=\"{}\"}} {}\n", endpoint, histogram.sum_ms()));
+            out.push_str(&format!("discovery_upstream_call_latency_ms_count{{endpoint=\"{}\"}} {}\n", endpoint, histogram.count()));
+        }
+
+        out.push_str("# HELP discovery_cycle_duration_ms Per-chain discovery cycle wall time in milliseconds\n");
+        out.push_str("# TYPE discovery_cycle_duration_ms gauge\n");
+        for (chain, histogram) in self.cycle_duration.lock().await.iter() {
+            out.push_str(&format!(
+                "discovery_cycle_duration_ms{{chain=\"{}\",quantile=\"0.5\"}} {}\n",
+                chain, histogram.percentile(0.5)
+            ));
+            out.push_str(&format!(
+                "discovery_cycle_duration_ms{{chain=\"{}\",quantile=\"0.9\"}} {}\n",
+                chain, histogram.percentile(0.9)
+            ));
+            out.push_str(&format!(
+                "discovery_cycle_duration_ms{{chain=\"{}\",quantile=\"0.99\"}} {}\n",
+                chain, histogram.percentile(0.99)
+            ));
+            out.push_str(&format!("discovery_cycle_duration_ms_sum{{chain=\"{}\"}} {}\n", chain, histogram.sum_ms()));
+            out.push_str(&format!("discovery_cycle_duration_ms_count{{chain=\"{}\"}} {}\n", chain, histogram.count()));
+        }
+
+        out
+    }
+}
+
+/// Minimal hand-rolled HTTP server that answers any request on `/metrics` with the current
+/// Prometheus snapshot and 404s everything else. No web framework dependency needed for a
+/// single read-only endpoint that doesn't outlive the orchestrator's own process.
+async fn serve_metrics(metrics: Arc<MetricsRegistry>, token: CancellationToken, port: u16) -> Result<()> {
+    let listener = TcpListener::bind(("0.0.0.0", port)).await?;
+    info!("📊 Metrics server listening on :{}/metrics", port);
+
+    loop {
+        let (mut socket, _) = tokio::select! {
+            _ = token.cancelled() => return Ok(()),
+            accepted = listener.accept() => match accepted {
+                Ok(accepted) => accepted,
+                Err(e) => {
+                    warn!("❌ Metrics server accept error: {}", e);
+                    continue;
+                }
+            },
+        };
+
+        let metrics = metrics.clone();
+        tokio::spawn(async move {
+            let mut buf = [0u8; 1024];
+            let n = match socket.read(&mut buf).await {
+                Ok(n) => n,
+                Err(_) => return,
+            };
+
+            // The request line is all we need: `GET /metrics HTTP/1.1`. Anything that
+            // doesn't parse as a request line, or whose path isn't `/metrics`, gets a 404
+            // rather than the metrics body.
+            let path = std::str::from_utf8(&buf[..n])
+                .ok()
+                .and_then(|request| request.lines().next())
+                .and_then(|request_line| request_line.split_whitespace().nth(1));
+
+            let response = if path == Some("/metrics") {
+                let body = metrics.render_prometheus().await;
+                format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+            } else {
+                let body = "Not Found";
+                format!(
+                    "HTTP/1.1 404 Not Found\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+            };
+            let _ = socket.write_all(response.as_bytes()).await;
+        });
+    }
+}
+
+/// Run a single chain's `logsSubscribe` WebSocket subscription until `token` is cancelled,
+/// reconnecting with exponential backoff whenever the socket drops or fails to connect.
+async fn run_log_subscription(
+    chain: String,
+    ws_url: String,
+    program_ids: Vec<String>,
+    token: CancellationToken,
+    redis_client: Arc<Mutex<Option<RedisClient>>>,
+    dedup_cache: Arc<DedupCache>,
+    watched_tokens: Arc<WatchedTokenRegistry>,
+    event_tx: broadcast::Sender<DiscoveryEvent>,
+) {
+    const MAX_BACKOFF: Duration = Duration::from_secs(60);
+    // Caps how many `getTransaction` resolutions are in flight at once. Without this, a burst
+    // of log notifications queues up unbounded HTTP round trips against the RPC provider and
+    // the read loop falls permanently behind the live log stream.
+    const MAX_CONCURRENT_RESOLVES: usize = 8;
+    // How often to send an additional subscription for the latest watched-token set, so tokens
+    // discovered after the initial subscribe still get picked up. Earlier subscriptions on the
+    // same connection are not unsubscribed; they stay active until the next reconnect.
+    const RESUBSCRIBE_INTERVAL: Duration = Duration::from_secs(30);
+
+    let mut backoff = Duration::from_secs(1);
+    // `logsSubscribe` only carries the signature + raw log lines, not the parsed swap -
+    // resolving mint/trader needs a `getTransaction` round trip against the chain's JSON-RPC
+    // HTTP endpoint, which normally lives at the same host as the WS endpoint.
+    let rpc_http_url = http_rpc_url_from_ws(&ws_url);
+    // Built once and reused for every `getTransaction` call across reconnects - a fresh
+    // `reqwest::Client` per call would rebuild its connection pool and TLS config from scratch
+    // every time, on top of being a full HTTP round trip already.
+    let http_client = reqwest::Client::new();
+    let resolve_semaphore = Arc::new(Semaphore::new(MAX_CONCURRENT_RESOLVES));
+
+    loop {
+        if token.is_cancelled() {
+            info!("🛑 Streaming subscription for chain {} stopping (orchestrator not running)", chain);
+            return;
+        }
+
+        info!("🔌 Connecting streaming logsSubscribe for chain {} at {}", chain, ws_url);
+        match connect_async(&ws_url).await {
+            Ok((mut ws_stream, _)) => {
+                backoff = Duration::from_secs(1);
+
+                // Scope `mentions` to the tokens the polling sources have already surfaced as
+                // worth watching. The DEX-program firehose (every Raydium/Orca/Meteora swap on
+                // the chain) is only subscribed when the watched set is empty at connect time;
+                // once subscribed it stays active on this connection until it reconnects, and
+                // its notifications are filtered by `is_watched` below.
+                let mut mentions = watched_tokens.snapshot().await;
+                if mentions.is_empty() {
+                    mentions = program_ids.clone();
+                }
+
+                if let Err(e) = send_logs_subscribe(&mut ws_stream, &mentions).await {
+                    warn!("❌ Failed to send logsSubscribe request for chain {}: {}", chain, e);
+                } else {
+                    let mut resubscribe_tick = tokio::time::interval(RESUBSCRIBE_INTERVAL);
+                    resubscribe_tick.tick().await; // first tick fires immediately; consume it
+
+                    'stream: loop {
+                        let next_message = tokio::select! {
+                            _ = token.cancelled() => {
+                                info!("🛑 Streaming subscription for chain {} stopping mid-stream", chain);
+                                return;
+                            }
+                            _ = resubscribe_tick.tick() => {
+                                let mut fresh_mentions = watched_tokens.snapshot().await;
+                                if fresh_mentions.is_empty() {
+                                    fresh_mentions = program_ids.clone();
+                                }
+                                if fresh_mentions != mentions {
+                                    if let Err(e) = send_logs_subscribe(&mut ws_stream, &fresh_mentions).await {
+                                        warn!("❌ Failed to resubscribe logsSubscribe for chain {}: {}", chain, e);
+                                        break 'stream;
+                                    }
+                                    debug!("🔄 Resubscribed streaming logsSubscribe for chain {} to {} mentions", chain, fresh_mentions.len());
+                                    mentions = fresh_mentions;
+                                }
+                                continue 'stream;
+                            }
+                            result = tokio::time::timeout(Duration::from_secs(30), ws_stream.next()) => result,
+                        };
+
+                        match next_message {
+                            Ok(Some(Ok(Message::Text(text)))) => {
+                                // Resolution is a `getTransaction` HTTP round trip - offload it to
+                                // its own task, gated by `resolve_semaphore`, so it can never block
+                                // the WS read loop from keeping up with the live log stream.
+                                let http_client = http_client.clone();
+                                let rpc_http_url = rpc_http_url.clone();
+                                let chain = chain.clone();
+                                let redis_client = redis_client.clone();
+                                let dedup_cache = dedup_cache.clone();
+                                let watched_tokens = watched_tokens.clone();
+                                let event_tx = event_tx.clone();
+                                let resolve_semaphore = resolve_semaphore.clone();
+
+                                tokio::spawn(async move {
+                                    let _permit = match resolve_semaphore.acquire_owned().await {
+                                        Ok(permit) => permit,
+                                        Err(_) => return,
+                                    };
+
+                                    let Some(pair) = parse_log_notification(&http_client, &rpc_http_url, &text, &chain).await else {
+                                        return;
+                                    };
+                                    if !watched_tokens.is_watched(&pair.token_address).await {
+                                        return;
+                                    }
+                                    let token_address = pair.token_address.clone();
+                                    let token_symbol = pair.token_symbol.clone();
+                                    match push_discovered_pairs(&redis_client, &dedup_cache, &[pair]).await {
+                                        Ok(pushed) if pushed > 0 => {
+                                            debug!("📤 Streamed 1 new wallet-token pair for chain {}", chain);
+                                            let _ = event_tx.send(DiscoveryEvent {
+                                                kind: DiscoveryEventKind::PairsEnqueued,
+                                                source: DiscoverySource::Streaming,
+                                                chain: chain.clone(),
+                                                token_address,
+                                                token_symbol,
+                                                count: pushed,
+                                                occurred_at: chrono::Utc::now(),
+                                            });
+                                        }
+                                        Ok(_) => {}
+                                        Err(e) => warn!("❌ Failed to push streamed pair for chain {}: {}", chain, e),
+                                    }
+                                });
+                            }
+                            Ok(Some(Ok(Message::Ping(payload)))) => {
+                                if let Err(e) = ws_stream.send(Message::Pong(payload)).await {
+                                    warn!("❌ Failed to send pong for chain {}: {}", chain, e);
+                                    break 'stream;
+                                }
+                            }
+                            Ok(Some(Ok(Message::Pong(_)))) => {}
+                            Ok(Some(Ok(Message::Close(_)))) | Ok(None) => {
+                                warn!("🔌 Streaming websocket closed for chain {}, reconnecting", chain);
+                                break 'stream;
+                            }
+                            Ok(Some(Err(e))) => {
+                                warn!("❌ Streaming websocket error for chain {}: {}", chain, e);
+                                break 'stream;
+                            }
+                            Ok(Some(Ok(_))) => {}
+                            Err(_elapsed) => {
+                                debug!("⏱️ No streaming messages for chain {} in 30s, connection still open", chain);
+                            }
+                        }
+                    }
+                }
+            }
+            Err(e) => {
+                warn!("❌ Failed to connect streaming websocket for chain {}: {}", chain, e);
+            }
+        }
+
+        info!("🔁 Reconnecting streaming subscription for chain {} in {:?}", chain, backoff);
+        tokio::select! {
+            _ = token.cancelled() => {
+                info!("🛑 Streaming subscription for chain {} stopping during reconnect backoff", chain);
+                return;
+            }
+            _ = tokio::time::sleep(backoff) => {}
+        }
+        backoff = (backoff * 2).min(MAX_BACKOFF);
+    }
+}
+
+/// Send (or re-send) the `logsSubscribe` request scoped to `mentions`. Solana's `mentions`
+/// filter only matches notifications whose transaction references one of the given pubkeys,
+/// so passing watched token mints here - rather than the DEX program ids - does the filtering
+/// server-side before a single notification reaches this process.
+async fn send_logs_subscribe<S>(ws_stream: &mut S, mentions: &[String]) -> Result<(), tokio_tungstenite::tungstenite::Error>
+where
+    S: futures_util::Sink<Message, Error = tokio_tungstenite::tungstenite::Error> + Unpin,
+{
+    let subscribe_request = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "logsSubscribe",
+        "params": [
+            { "mentions": mentions },
+            { "commitment": "confirmed" }
+        ]
+    });
+    ws_stream.send(Message::Text(subscribe_request.to_string())).await
+}
+
+/// Derive the JSON-RPC HTTP endpoint for a chain's WebSocket `logsSubscribe` URL. Solana RPC
+/// providers (Helius, QuickNode, Triton, ...) serve both protocols off the same host, just
+/// with `ws(s)` swapped for `http(s)` - there's no separate config knob for it.
+fn http_rpc_url_from_ws(ws_url: &str) -> String {
+    if let Some(rest) = ws_url.strip_prefix("wss://") {
+        format!("https://{}", rest)
+    } else if let Some(rest) = ws_url.strip_prefix("ws://") {
+        format!("http://{}", rest)
+    } else {
+        ws_url.to_string()
+    }
+}
+
+/// Best-effort resolution of a swap's mint and trader wallet from a `logsSubscribe`
+/// notification. Raydium AMM / Orca Whirlpool / Meteora emit opaque base64 `Program data:`
+/// instructions, not human-readable `mint:`/`trader:` log lines, so the only reliable way to
+/// recover a swap's participants from a log notification is to look the transaction itself
+/// back up by its signature.
+async fn parse_log_notification(http_client: &reqwest::Client, rpc_http_url: &str, text: &str, chain: &str) -> Option<DiscoveredWalletToken> {
+    let notification: Value = serde_json::from_str(text).ok()?;
+    let result = notification.get("params")?.get("result")?.get("value")?;
+    let signature = result.get("signature")?.as_str()?;
+
+    // `err: null` means the transaction landed successfully; anything else didn't move tokens.
+    if !result.get("err").map(Value::is_null).unwrap_or(true) {
+        return None;
+    }
+
+    resolve_swap_via_transaction(http_client, rpc_http_url, signature, chain).await
+}
+
+/// Fetch `signature`'s parsed transaction via `getTransaction` and pull out the trading
+/// wallet (the fee payer, `accountKeys[0]`) and the first non-SOL mint listed in
+/// `postTokenBalances` (balances are not compared against `preTokenBalances`), the same pieces `GeneralTraderTransaction` carries for the REST polling path. Takes the
+/// caller's `reqwest::Client` rather than building its own, since this is called once per
+/// streamed notification and a fresh client per call would rebuild a connection pool and TLS
+/// config for every swap.
+async fn resolve_swap_via_transaction(http_client: &reqwest::Client, rpc_http_url: &str, signature: &str, chain: &str) -> Option<DiscoveredWalletToken> {
+    const SOL_MINT: &str = "So11111111111111111111111111111111111111112";
+
+    let request_body = json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "getTransaction",
+        "params": [signature, { "encoding": "jsonParsed", "maxSupportedTransactionVersion": 0 }],
+    });
+
+    let response: Value = http_client
+        .post(rpc_http_url)
+        .json(&request_body)
+        .send()
+        .await
+        .ok()?
+        .json()
+        .await
+        .ok()?;
+
+    let tx = response.get("result")?;
+    let trader = tx
+        .get("transaction")?
+        .get("message")?
+        .get("accountKeys")?
+        .as_array()?
+        .first()?
+        .get("pubkey")
+        .and_then(Value::as_str)?
+        .to_string();
+
+    let meta = tx.get("meta")?;
+    let mint = meta
+        .get("postTokenBalances")?
+        .as_array()?
+        .iter()
+        .filter_map(|balance| balance.get("mint")?.as_str())
+        .find(|mint| *mint != SOL_MINT)?
+        .to_string();
+
+    debug!("📝 Resolved streaming swap {} on chain {}: mint={}, trader={}", signature, chain, mint, trader);
+
+    Some(DiscoveredWalletToken {
+        wallet_address: trader,
+        chain: chain.to_string(),
+        token_address: mint,
+        token_symbol: "STREAMED".to_string(),
+        trader_volume_usd: 0.0,
+        trader_trades: 1,
+        discovered_at: chrono::Utc::now(),
+    })
+}
+
+/// Push already-built wallet-token pairs through the Redis dedup queue. Shared by the
+/// streaming discovery source, which runs outside the orchestrator's `&self` methods.
+async fn push_discovered_pairs(
+    redis_client: &Arc<Mutex<Option<RedisClient>>>,
+    dedup_cache: &Arc<DedupCache>,
+    pairs: &[DiscoveredWalletToken],
+) -> Result<usize> {
+    if pairs.is_empty() {
+        return Ok(0);
+    }
+
+    let unseen = dedup_cache.filter_unseen(pairs).await;
+    if unseen.is_empty() {
+        return Ok(0);
+    }
+
+    let redis = redis_client.lock().await;
+    if let Some(ref redis_client) = *redis {
+        let pushed = redis_client.push_discovered_wallet_token_pairs_deduplicated(&unseen).await?;
+        dedup_cache.record_pushed(&unseen).await;
+        Ok(pushed)
+    } else {
+        warn!("⚠️ Redis client not available, cannot push streamed wallet-token pair");
+        Ok(0)
+    }
+}
+
 /// Statistics about the discovery process
 #[derive(Debug, Clone)]
 pub struct DiscoveryStats {
@@ -840,6 +2067,65 @@ pub struct DiscoveryStats {
     pub wallet_token_pairs_discovered: u32,
     pub new_listing_tokens_discovered: u32,
     pub new_listing_wallets_discovered: u32,
+    pub dedup_cache_hits: u64,
+    pub dedup_cache_misses: u64,
+    /// p50/p90/p99 + count for every instrumented upstream call and per-chain cycle, so
+    /// operators can see which stage dominates a cycle without scraping `/metrics` separately.
+    pub latency_histograms: Vec<LatencyHistogramSnapshot>,
+    /// Tokens whose traders were withheld by `passes_depth_check` because realizable
+    /// order-book depth fell short of `trader_filter.min_depth_to_liquidity_ratio`.
+    pub tokens_rejected_shallow_depth: u32,
+}
+
+/// A single labeled histogram's quantiles at the moment `get_discovery_stats` was called.
+#[derive(Debug, Clone)]
+pub struct LatencyHistogramSnapshot {
+    pub label: String,
+    pub p50_ms: u64,
+    pub p90_ms: u64,
+    pub p99_ms: u64,
+    pub count: u64,
+}
+
+/// Which metric a `PriceTrigger` watches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TriggerMetric {
+    Price,
+    Liquidity,
+}
+
+/// Which way `target` must be crossed before a `PriceTrigger` fires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TriggerDirection {
+    Above,
+    Below,
+}
+
+/// A deferred re-evaluation for a token that doesn't yet meet volume/liquidity filters,
+/// modeled on mango-v4's arbitrary-pair limit/stop-loss orders: instead of discarding the
+/// token outright, park it here and only fetch + enqueue its top traders once `metric`
+/// crosses `target` in `direction`. Persisted in Redis rather than in-process state so
+/// triggers survive restarts and are checked whenever the `price_trigger` schedule is due, in
+/// `check_price_triggers`, instead of re-scanning the full trending set every time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PriceTrigger {
+    pub token_address: String,
+    pub chain: String,
+    pub token_symbol: String,
+    pub metric: TriggerMetric,
+    pub target: f64,
+    pub direction: TriggerDirection,
+    pub registered_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl PriceTrigger {
+    /// Whether `current` has crossed `self.target` in `self.direction`.
+    fn crossed(&self, current: f64) -> bool {
+        match self.direction {
+            TriggerDirection::Above => current >= self.target,
+            TriggerDirection::Below => current <= self.target,
+        }
+    }
 }
 
 /// Processed swap transaction for BirdEye data analysis
@@ -854,13 +2140,102 @@ pub struct ProcessedSwap {
     pub tx_hash: String,
     pub timestamp: i64,
     pub source: String,
+    /// True when BirdEye's reported price was missing, zero, or too far from the per-token
+    /// stable price and `price_per_token` was substituted with the stable price instead.
+    /// The P&L engine should weight or discard swaps where this is set.
+    pub price_was_substituted: bool,
+}
+
+/// Maximum fraction a fresh BirdEye price may deviate from a token's stable price before
+/// it's treated as bad data and the stable price is substituted instead.
+const STABLE_PRICE_MAX_DEVIATION_RATIO: f64 = 0.2;
+/// Maximum fraction of the stable price it's allowed to move per second, so one noisy
+/// observation can't yank it around - mirrors mango-v4's oracle stable-price clamp.
+const STABLE_PRICE_MAX_MOVE_PER_SEC: f64 = 0.05;
+
+struct StablePriceState {
+    stable: Decimal,
+    last_update_unix: i64,
+}
+
+/// Per-token stable-price EMA, borrowed from mango-v4's oracle handling: initialized only on
+/// the first valid (non-zero) observation for a token - never to zero, which was the bug
+/// mango-v4 fixed - and thereafter nudged toward each new observation by a delta clamped to
+/// `stable * STABLE_PRICE_MAX_MOVE_PER_SEC * dt`. A fresh tracker only smooths prices within
+/// the batch it's given; callers that want continuity across batches should keep one around
+/// and pass it via `from_birdeye_transactions_with_tracker`.
+#[derive(Default)]
+pub struct StablePriceTracker {
+    states: HashMap<String, StablePriceState>,
+}
+
+impl StablePriceTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolve the price to use for `token` given a fresh `observed` reading (`None` if
+    /// BirdEye didn't report one). Returns `(price, was_substituted)`. Tokens with no prior
+    /// observation pass the raw reading through untouched, even if it's zero or missing.
+    fn resolve(&mut self, token: &str, observed: Option<Decimal>, now_unix: i64) -> (Decimal, bool) {
+        match self.states.get_mut(token) {
+            None => {
+                if let Some(price) = observed {
+                    if price > Decimal::ZERO {
+                        self.states.insert(token.to_string(), StablePriceState { stable: price, last_update_unix: now_unix });
+                    }
+                }
+                (observed.unwrap_or_default(), false)
+            }
+            Some(state) => {
+                let valid = observed.filter(|price| *price > Decimal::ZERO);
+                let deviates = match valid {
+                    Some(price) => {
+                        let deviation_ratio = ((price - state.stable).abs() / state.stable)
+                            .to_string()
+                            .parse::<f64>()
+                            .unwrap_or(f64::MAX);
+                        deviation_ratio > STABLE_PRICE_MAX_DEVIATION_RATIO
+                    }
+                    None => true,
+                };
+
+                // Every valid observation nudges the stable price, including one that deviates
+                // too far to be used directly; a substituted swap gets the pre-nudge value.
+                let stable_before = state.stable;
+                if let Some(price) = valid {
+                    let dt = (now_unix - state.last_update_unix).max(0) as f64;
+                    let max_delta = state.stable.abs() * Decimal::from_f64_retain(STABLE_PRICE_MAX_MOVE_PER_SEC * dt).unwrap_or_default();
+                    let delta = (price - state.stable).clamp(-max_delta, max_delta);
+                    state.stable += delta;
+                    state.last_update_unix = now_unix;
+                }
+
+                match valid {
+                    Some(price) if !deviates => (price, false),
+                    _ => (stable_before, true),
+                }
+            }
+        }
+    }
 }
 
 impl ProcessedSwap {
-    /// Process BirdEye transactions into ProcessedSwap format
+    /// Process BirdEye transactions into ProcessedSwap format. Uses a fresh, batch-scoped
+    /// `StablePriceTracker` - callers that want the stable price to persist across calls
+    /// should use `from_birdeye_transactions_with_tracker` instead.
     pub fn from_birdeye_transactions(transactions: &[GeneralTraderTransaction]) -> Result<Vec<ProcessedSwap>> {
+        Self::from_birdeye_transactions_with_tracker(transactions, &mut StablePriceTracker::new())
+    }
+
+    /// Process BirdEye transactions into ProcessedSwap format, resolving stale/missing prices
+    /// against `tracker`'s per-token stable price instead of trusting BirdEye directly.
+    pub fn from_birdeye_transactions_with_tracker(
+        transactions: &[GeneralTraderTransaction],
+        tracker: &mut StablePriceTracker,
+    ) -> Result<Vec<ProcessedSwap>> {
         let mut processed_swaps = Vec::new();
-        
+
         for tx in transactions {
             // Determine which token is being sold (from) and which is being bought (to)
             let (token_in, amount_in, token_out, amount_out) = if tx.quote.type_swap == "from" {
@@ -892,29 +2267,32 @@ impl ProcessedSwap {
                 Decimal::from_f64_retain(tx.quote_price).unwrap_or_default() * amount_in
             };
             
-            let price_per_token = if token_out == sol_mint {
-                // Selling token for SOL
-                if amount_out > Decimal::ZERO {
+            let (price_per_token, price_was_substituted) = if token_out == sol_mint {
+                // Selling token for SOL - derived directly from amounts, no BirdEye price involved
+                let price = if amount_out > Decimal::ZERO {
                     amount_out / amount_in
                 } else {
                     Decimal::ZERO
-                }
+                };
+                (price, false)
             } else if token_in == sol_mint {
                 // Buying token with SOL
-                tx.base.price.map(|p| Decimal::from_f64_retain(p).unwrap_or_default())
-                    .unwrap_or_else(|| {
-                        if amount_in > Decimal::ZERO {
-                            amount_out / amount_in
-                        } else {
-                            Decimal::ZERO
-                        }
-                    })
+                let observed = tx.base.price.map(|p| Decimal::from_f64_retain(p).unwrap_or_default());
+                let (price, substituted) =
+                    tracker.resolve(&tx.base.address, observed, tx.block_unix_time);
+                if price > Decimal::ZERO {
+                    (price, substituted)
+                } else if amount_in > Decimal::ZERO {
+                    (amount_out / amount_in, substituted)
+                } else {
+                    (Decimal::ZERO, substituted)
+                }
             } else {
                 // Token to token swap - use base price
-                tx.base.price.map(|p| Decimal::from_f64_retain(p).unwrap_or_default())
-                    .unwrap_or_default()
+                let observed = tx.base.price.map(|p| Decimal::from_f64_retain(p).unwrap_or_default());
+                tracker.resolve(&tx.base.address, observed, tx.block_unix_time)
             };
-            
+
             processed_swaps.push(ProcessedSwap {
                 token_in,
                 token_out,
@@ -925,6 +2303,7 @@ impl ProcessedSwap {
                 tx_hash: tx.tx_hash.clone(),
                 timestamp: tx.block_unix_time,
                 source: tx.source.clone(),
+                price_was_substituted,
             });
         }
         
@@ -936,4 +2315,92 @@ impl ProcessedSwap {
     // New P&L engine uses GeneralTraderTransaction directly with embedded prices
 }
 
-// Tests removed - will use integration tests with SystemConfig
\ No newline at end of file
+// Tests removed - will use integration tests with SystemConfig
+
+#[cfg(test)]
+mod stable_price_tracker_tests {
+    use super::*;
+
+    #[test]
+    fn first_zero_or_missing_observation_never_initializes_stable_to_zero() {
+        let mut tracker = StablePriceTracker::new();
+
+        let (price, substituted) = tracker.resolve("TOKEN", Some(Decimal::ZERO), 1_000);
+        assert_eq!(price, Decimal::ZERO);
+        assert!(!substituted);
+
+        let (price, substituted) = tracker.resolve("TOKEN", None, 1_001);
+        assert_eq!(price, Decimal::ZERO);
+        assert!(!substituted);
+
+        // Still uninitialized - a later valid observation starts tracking fresh rather than
+        // deviating against a phantom zero stable price.
+        let (price, substituted) = tracker.resolve("TOKEN", Some(Decimal::new(5, 0)), 1_002);
+        assert_eq!(price, Decimal::new(5, 0));
+        assert!(!substituted);
+    }
+
+    #[test]
+    fn no_prior_observation_passes_raw_reading_through_untouched() {
+        let mut tracker = StablePriceTracker::new();
+        let (price, substituted) = tracker.resolve("TOKEN", Some(Decimal::new(123, 2)), 1_000);
+        assert_eq!(price, Decimal::new(123, 2));
+        assert!(!substituted);
+    }
+
+    #[test]
+    fn large_deviation_from_stable_substitutes_the_stable_price() {
+        let mut tracker = StablePriceTracker::new();
+        tracker.resolve("TOKEN", Some(Decimal::new(100, 0)), 1_000);
+
+        // +50% in one tick is well past STABLE_PRICE_MAX_DEVIATION_RATIO (20%).
+        let (price, substituted) = tracker.resolve("TOKEN", Some(Decimal::new(150, 0)), 1_001);
+        assert_eq!(price, Decimal::new(100, 0));
+        assert!(substituted);
+    }
+
+    #[test]
+    fn missing_observation_after_init_substitutes_the_stable_price() {
+        let mut tracker = StablePriceTracker::new();
+        tracker.resolve("TOKEN", Some(Decimal::new(100, 0)), 1_000);
+
+        let (price, substituted) = tracker.resolve("TOKEN", None, 1_001);
+        assert_eq!(price, Decimal::new(100, 0));
+        assert!(substituted);
+    }
+
+    #[test]
+    fn small_move_nudges_stable_price_clamped_per_second() {
+        let mut tracker = StablePriceTracker::new();
+        tracker.resolve("TOKEN", Some(Decimal::new(100, 0)), 1_000);
+
+        // +10% is within the 20% deviation band, so it's accepted and returned raw, but the
+        // *stable* price itself can only move by STABLE_PRICE_MAX_MOVE_PER_SEC (5%) per second
+        // elapsed - here dt=1s, so the stable price can shift by at most 5.
+        let (price, substituted) = tracker.resolve("TOKEN", Some(Decimal::new(110, 0)), 1_001);
+        assert_eq!(price, Decimal::new(110, 0));
+        assert!(!substituted);
+        assert_eq!(
+            tracker.states["TOKEN"].stable,
+            Decimal::new(100, 0) + Decimal::new(100, 0) * Decimal::from_f64_retain(STABLE_PRICE_MAX_MOVE_PER_SEC).unwrap()
+        );
+
+        let (next_price, next_substituted) = tracker.resolve("TOKEN", Some(Decimal::new(110, 0)), 1_002);
+        assert_eq!(next_price, Decimal::new(110, 0));
+        assert!(!next_substituted);
+    }
+
+    #[test]
+    fn negative_dt_is_clamped_to_zero_instead_of_widening_the_move_budget() {
+        let mut tracker = StablePriceTracker::new();
+        tracker.resolve("TOKEN", Some(Decimal::new(100, 0)), 1_000);
+
+        // An out-of-order observation with an earlier timestamp than the last update must not
+        // get a negative `dt` that would (via `* dt`) flip the clamp bound's sign and let the
+        // stable price move further than intended, or panic on an unexpected Decimal range.
+        let (price, substituted) = tracker.resolve("TOKEN", Some(Decimal::new(105, 0)), 999);
+        assert_eq!(price, Decimal::new(105, 0));
+        assert!(!substituted);
+        assert_eq!(tracker.states["TOKEN"].stable, Decimal::new(100, 0));
+    }
+}
\ No newline at end of file